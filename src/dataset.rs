@@ -1,21 +1,163 @@
-use std::{ffi::CString, ffi::NulError, path::Path, ptr, sync::Once};
+use std::{
+    ffi::CString,
+    ops::{Deref, DerefMut},
+    path::Path,
+    ptr,
+    sync::Once,
+};
 
-use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
+use crate::utils::{
+    _creation_options_list, _last_cpl_err, _last_null_pointer_err, _opt_list_ptr, _string,
+    CStringList,
+};
 use crate::{
-    gdal_major_object::MajorObject, raster::RasterBand, spatial_ref::SpatialRef, vector::Layer,
-    Driver, Metadata,
+    gdal_major_object::MajorObject,
+    progress::{_progress_args, ProgressFn},
+    raster::{
+        check_buffer_len, check_window, CGcpList, Gcp, GdalType, Interleave, MultiBandBuffer,
+        RasterBand, RasterCreationOption,
+    },
+    spatial_ref::SpatialRef,
+    vector::{Geometry, Layer, ResultSet, SqlDialect},
+    Driver, GeoTransform, Metadata,
 };
+use bitflags::bitflags;
 use gdal_sys::{
-    self, CPLErr, GDALAccess, GDALDatasetH, GDALMajorObjectH, OGRLayerH, OGRwkbGeometryType,
+    self, CPLErr, GDALDatasetH, GDALMajorObjectH, GDALRWFlag, OGRErr, OGRLayerH, OGRwkbGeometryType,
 };
-use libc::{c_double, c_int};
+use libc::{c_int, c_uint, c_void};
 use ptr::null_mut;
 
+#[cfg(feature = "ndarray")]
+use ndarray::Array3;
+
 use crate::errors::*;
 
-pub type GeoTransform = [c_double; 6];
 static START: Once = Once::new();
 
+bitflags! {
+    /// Open flags of `Dataset::open_ex`, mirroring GDAL's `GDAL_OF_*` constants.
+    #[derive(Default)]
+    pub struct GdalOpenFlags: c_uint {
+        /// Open in read-only mode (default).
+        const GDAL_OF_READONLY = 0x00;
+        /// Open in update mode.
+        const GDAL_OF_UPDATE = 0x01;
+        /// Allow raster and vector drivers to be used.
+        const GDAL_OF_ALL = 0x00;
+        /// Allow raster drivers to be used.
+        const GDAL_OF_RASTER = 0x02;
+        /// Allow vector drivers to be used.
+        const GDAL_OF_VECTOR = 0x04;
+        /// Allow gnm drivers to be used.
+        const GDAL_OF_GNM = 0x08;
+        /// Allow multidimensional raster drivers to be used (GDAL >= 3.1).
+        const GDAL_OF_MULTIDIM_RASTER = 0x10;
+        /// Open in shared mode.
+        const GDAL_OF_SHARED = 0x20;
+        /// Emit error message in case of failed open.
+        const GDAL_OF_VERBOSE_ERROR = 0x40;
+        /// Open as internal dataset. Such dataset isn't registered in the global list
+        /// of opened dataset.
+        const GDAL_OF_INTERNAL = 0x80;
+        /// Let GDAL decide if a array-based or hashset-based storage strategy for
+        /// cached blocks must be used.
+        const GDAL_OF_DEFAULT_BLOCK_ACCESS = 0;
+        /// Use a array-based storage strategy for cached blocks.
+        const GDAL_OF_ARRAY_BLOCK_ACCESS = 0x100;
+        /// Use a hashset-based storage strategy for cached blocks.
+        const GDAL_OF_HASHSET_BLOCK_ACCESS = 0x200;
+    }
+}
+
+/// Options of `Dataset::open_ex`.
+///
+/// ```
+/// use std::path::Path;
+/// use gdal::{Dataset, DatasetOptions};
+///
+/// let options = DatasetOptions::new()
+///     .vector()
+///     .allowed_drivers(&["GeoJSON"])
+///     .open_options(&["FLATTEN_NESTED_ATTRIBUTES=YES"]);
+/// let dataset = Dataset::open_ex(Path::new("fixtures/roads.geojson"), options).unwrap();
+/// assert_eq!(dataset.layer_count(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct DatasetOptions<'a> {
+    pub open_flags: GdalOpenFlags,
+    pub allowed_drivers: Option<&'a [&'a str]>,
+    pub open_options: Option<&'a [&'a str]>,
+    pub sibling_files: Option<&'a [&'a str]>,
+}
+
+impl<'a> DatasetOptions<'a> {
+    /// Read-only options without any driver restriction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the open flags.
+    pub fn flags(mut self, open_flags: GdalOpenFlags) -> Self {
+        self.open_flags = open_flags;
+        self
+    }
+
+    /// Only consider raster drivers.
+    pub fn raster(mut self) -> Self {
+        self.open_flags |= GdalOpenFlags::GDAL_OF_RASTER;
+        self
+    }
+
+    /// Only consider vector drivers.
+    pub fn vector(mut self) -> Self {
+        self.open_flags |= GdalOpenFlags::GDAL_OF_VECTOR;
+        self
+    }
+
+    /// Only consider multidimensional raster drivers (GDAL >= 3.1).
+    pub fn multidim_raster(mut self) -> Self {
+        self.open_flags |= GdalOpenFlags::GDAL_OF_MULTIDIM_RASTER;
+        self
+    }
+
+    /// Reuse an already opened dataset for the same file, if there is one.
+    pub fn shared(mut self) -> Self {
+        self.open_flags |= GdalOpenFlags::GDAL_OF_SHARED;
+        self
+    }
+
+    /// Let GDAL emit an error message if the dataset can't be opened.
+    pub fn verbose_error(mut self) -> Self {
+        self.open_flags |= GdalOpenFlags::GDAL_OF_VERBOSE_ERROR;
+        self
+    }
+
+    /// Open in update mode.
+    pub fn update(mut self) -> Self {
+        self.open_flags |= GdalOpenFlags::GDAL_OF_UPDATE;
+        self
+    }
+
+    /// Restrict the drivers that may open the dataset, e.g. `&["GTiff", "PNG"]`.
+    pub fn allowed_drivers(mut self, allowed_drivers: &'a [&'a str]) -> Self {
+        self.allowed_drivers = Some(allowed_drivers);
+        self
+    }
+
+    /// Driver specific open options, e.g. `&["FLATTEN_NESTED_ATTRIBUTES=YES"]`.
+    pub fn open_options(mut self, open_options: &'a [&'a str]) -> Self {
+        self.open_options = Some(open_options);
+        self
+    }
+
+    /// Files next to the dataset, so that GDAL doesn't need to probe the file system.
+    pub fn sibling_files(mut self, sibling_files: &'a [&'a str]) -> Self {
+        self.sibling_files = Some(sibling_files);
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct Dataset {
     c_dataset: GDALDatasetH,
@@ -46,97 +188,28 @@ impl Dataset {
     }
 
     pub fn open(path: &Path) -> Result<Dataset> {
-        Self::open_ex(path, None, None, None, None)
+        Self::open_ex(path, DatasetOptions::default())
     }
 
-    pub fn open_ex(
-        path: &Path,
-        open_flags: Option<GDALAccess::Type>,
-        allowed_drivers: Option<&[&str]>, // TODO: use parameters
-        open_options: Option<&[&str]>,
-        sibling_files: Option<&[&str]>,
-    ) -> Result<Dataset> {
+    /// Open a dataset with extended options. See `DatasetOptions` for the
+    /// available flags, driver restrictions, open options and sibling files.
+    pub fn open_ex(path: &Path, options: DatasetOptions) -> Result<Dataset> {
         _register_drivers();
         let filename = path.to_string_lossy();
         let c_filename = CString::new(filename.as_ref())?;
-        let c_open_flags = open_flags.unwrap_or(GDALAccess::GA_ReadOnly); // This defaults to GdalAccess::GA_ReadOnly
 
-        // handle driver params:
         // we need to keep the CStrings and the pointers around
-        let c_allowed_drivers = allowed_drivers.map(|d| {
-            d.into_iter()
-                .map(|&s| CString::new(s))
-                .collect::<std::result::Result<Vec<CString>, NulError>>()
-        });
-        let c_drivers_vec = match c_allowed_drivers {
-            Some(Err(e)) => return Err(e.into()),
-            Some(Ok(c_drivers_vec)) => c_drivers_vec,
-            None => Vec::from([]),
-        };
-        let mut c_drivers_ptrs = c_drivers_vec.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
-        c_drivers_ptrs.push(ptr::null());
-
-        let c_drivers_ptr = if allowed_drivers.is_some() {
-            c_drivers_ptrs.as_ptr()
-        } else {
-            ptr::null()
-        };
-
-        // handle open options params:
-        // we need to keep the CStrings and the pointers around
-        let c_open_options = open_options.map(|d| {
-            d.into_iter()
-                .map(|&s| CString::new(s))
-                .collect::<std::result::Result<Vec<CString>, NulError>>()
-        });
-        let c_open_options_vec = match c_open_options {
-            Some(Err(e)) => return Err(e.into()),
-            Some(Ok(c_open_options_vec)) => c_open_options_vec,
-            None => Vec::from([]),
-        };
-        let mut c_open_options_ptrs = c_open_options_vec
-            .iter()
-            .map(|s| s.as_ptr())
-            .collect::<Vec<_>>();
-        c_open_options_ptrs.push(ptr::null());
-
-        let c_open_options_ptr = if open_options.is_some() {
-            c_open_options_ptrs.as_ptr()
-        } else {
-            ptr::null()
-        };
-
-        // handle sibling files params:
-        // we need to keep the CStrings and the pointers around
-        let c_sibling_files = sibling_files.map(|d| {
-            d.into_iter()
-                .map(|&s| CString::new(s))
-                .collect::<std::result::Result<Vec<CString>, NulError>>()
-        });
-        let c_sibling_files_vec = match c_sibling_files {
-            Some(Err(e)) => return Err(e.into()),
-            Some(Ok(c_sibling_files_vec)) => c_sibling_files_vec,
-            None => Vec::from([]),
-        };
-        let mut c_sibling_files_ptrs = c_sibling_files_vec
-            .iter()
-            .map(|s| s.as_ptr())
-            .collect::<Vec<_>>();
-        c_sibling_files_ptrs.push(ptr::null());
-
-        let c_sibling_files_ptr = if sibling_files.is_some() {
-            c_sibling_files_ptrs.as_ptr()
-        } else {
-            ptr::null()
-        };
+        let c_allowed_drivers = options.allowed_drivers.map(CStringList::new).transpose()?;
+        let c_open_options = options.open_options.map(CStringList::new).transpose()?;
+        let c_sibling_files = options.sibling_files.map(CStringList::new).transpose()?;
 
         let c_dataset = unsafe {
             gdal_sys::GDALOpenEx(
                 c_filename.as_ptr(),
-                c_open_flags,
-                c_drivers_ptr,
-                c_open_options_ptr,
-                c_sibling_files_ptr,
+                options.open_flags.bits(),
+                _opt_list_ptr(&c_allowed_drivers) as _,
+                _opt_list_ptr(&c_open_options) as _,
+                _opt_list_ptr(&c_sibling_files) as _,
             )
         };
         if c_dataset.is_null() {
@@ -164,17 +237,106 @@ impl Dataset {
         Ok(())
     }
 
+    /// Get the ground control points of the dataset.
+    pub fn gcps(&self) -> Vec<Gcp> {
+        let count = unsafe { gdal_sys::GDALGetGCPCount(self.c_dataset) };
+        let c_gcps = unsafe { gdal_sys::GDALGetGCPs(self.c_dataset) };
+        if count <= 0 || c_gcps.is_null() {
+            return Vec::new();
+        }
+        unsafe { std::slice::from_raw_parts(c_gcps, count as usize) }
+            .iter()
+            .map(Gcp::from_c_gcp)
+            .collect()
+    }
+
+    /// Get the projection of the ground control points, as WKT.
+    ///
+    /// Returns `None` if the dataset doesn't have any GCPs.
+    pub fn gcp_projection(&self) -> Option<String> {
+        let rv = unsafe { gdal_sys::GDALGetGCPProjection(self.c_dataset) };
+        if rv.is_null() {
+            return None;
+        }
+        let projection = _string(rv);
+        if projection.is_empty() {
+            return None;
+        }
+        Some(projection)
+    }
+
+    /// Set the ground control points of the dataset, along with their projection.
+    pub fn set_gcps(&self, gcps: &[Gcp], spatial_ref: &SpatialRef) -> Result<()> {
+        let c_gcps = CGcpList::new(gcps)?;
+        let c_projection = CString::new(spatial_ref.to_wkt()?)?;
+        let rv = unsafe {
+            gdal_sys::GDALSetGCPs(
+                self.c_dataset,
+                c_gcps.len() as c_int,
+                c_gcps.as_ptr(),
+                c_projection.as_ptr(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
     pub fn create_copy(&self, driver: &Driver, filename: &str) -> Result<Dataset> {
+        self.create_copy_with_options(driver, filename, &[])
+    }
+
+    /// Copy this dataset with `driver`, passing creation options such as
+    /// `COMPRESS=DEFLATE` or `BIGTIFF=YES` to it.
+    ///
+    /// The options are validated against the driver's creation option list first.
+    pub fn create_copy_with_options(
+        &self,
+        driver: &Driver,
+        filename: &str,
+        options: &[RasterCreationOption],
+    ) -> Result<Dataset> {
+        self._create_copy(driver, filename, options, None)
+    }
+
+    /// Like `create_copy_with_options`, reporting the progress of the copy to `progress`.
+    ///
+    /// The copy is cancelled if `progress` returns `ProgressStatus::Abort`.
+    pub fn create_copy_with_progress(
+        &self,
+        driver: &Driver,
+        filename: &str,
+        options: &[RasterCreationOption],
+        progress: &mut ProgressFn,
+    ) -> Result<Dataset> {
+        self._create_copy(driver, filename, options, Some(progress))
+    }
+
+    fn _create_copy(
+        &self,
+        driver: &Driver,
+        filename: &str,
+        options: &[RasterCreationOption],
+        mut progress: Option<&mut ProgressFn>,
+    ) -> Result<Dataset> {
         let c_filename = CString::new(filename)?;
+        let c_options = if options.is_empty() {
+            None
+        } else {
+            driver.validate_creation_options(options)?;
+            Some(_creation_options_list(options)?)
+        };
+        let (c_progress, c_progress_arg) = _progress_args(progress.as_mut());
         let c_dataset = unsafe {
             gdal_sys::GDALCreateCopy(
                 driver.c_driver(),
                 c_filename.as_ptr(),
                 self.c_dataset,
                 0,
-                ptr::null_mut(),
-                None,
-                ptr::null_mut(),
+                _opt_list_ptr(&c_options),
+                c_progress,
+                c_progress_arg,
             )
         };
         if c_dataset.is_null() {
@@ -200,6 +362,255 @@ impl Dataset {
         }
     }
 
+    /// Read several bands into a slice. T implements 'GdalType'
+    ///
+    /// # Arguments
+    /// * window - the window position from top left
+    /// * window_size - the window size (GDAL will interpolate data if window_size != buffer_size)
+    /// * size - the desired size to read
+    /// * bands - the indices of the bands to read, starting at 1
+    /// * interleave - the order of the band values in the buffer
+    /// * buffer - a slice to hold the data (length must equal product of size parameter and band count)
+    pub fn read_into_slice<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+        bands: &[isize],
+        interleave: Interleave,
+        buffer: &mut [T],
+    ) -> Result<()> {
+        check_window(window, window_size, self.raster_size())?;
+        check_buffer_len(buffer.len(), size, bands.len())?;
+        self._raster_io::<T>(
+            GDALRWFlag::GF_Read,
+            window,
+            window_size,
+            size,
+            bands,
+            interleave,
+            buffer.as_mut_ptr() as *mut c_void,
+        )
+    }
+
+    /// Read a 'MultiBandBuffer<T>' from several bands. T implements 'GdalType'
+    ///
+    /// # Arguments
+    /// * window - the window position from top left
+    /// * window_size - the window size (GDAL will interpolate data if window_size != buffer_size)
+    /// * size - the desired size of the 'MultiBandBuffer'
+    /// * bands - the indices of the bands to read, starting at 1
+    /// * interleave - the order of the band values in the buffer
+    pub fn read_as<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+        bands: &[isize],
+        interleave: Interleave,
+    ) -> Result<MultiBandBuffer<T>> {
+        check_window(window, window_size, self.raster_size())?;
+        let len = size.0 * size.1 * bands.len();
+
+        let mut data: Vec<T> = Vec::with_capacity(len);
+        self._raster_io::<T>(
+            GDALRWFlag::GF_Read,
+            window,
+            window_size,
+            size,
+            bands,
+            interleave,
+            data.as_mut_ptr() as *mut c_void,
+        )?;
+        // Safety: GDALDatasetRasterIO has written all len elements into the allocated capacity
+        unsafe {
+            data.set_len(len);
+        };
+
+        Ok(MultiBandBuffer::new(size, bands.len(), interleave, data))
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Read a 'Array3<T>' from several bands. T implements 'GdalType'.
+    ///
+    /// # Arguments
+    /// * window - the window position from top left
+    /// * window_size - the window size (GDAL will interpolate data if window_size != array_size)
+    /// * array_size - the desired size of the 'Array'
+    /// * bands - the indices of the bands to read, starting at 1
+    /// * interleave - the order of the band values in the array
+    /// # Docs
+    /// The array shape is (bands, rows, cols) for `Interleave::Band`
+    /// and (rows, cols, bands) for `Interleave::Pixel`.
+    pub fn read_as_array<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        array_size: (usize, usize),
+        bands: &[isize],
+        interleave: Interleave,
+    ) -> Result<Array3<T>> {
+        let data = self.read_as::<T>(window, window_size, array_size, bands, interleave)?;
+
+        let shape = match interleave {
+            Interleave::Band => (bands.len(), array_size.1, array_size.0),
+            Interleave::Pixel => (array_size.1, array_size.0, bands.len()),
+        };
+        Ok(Array3::from_shape_vec(shape, data.data)?)
+    }
+
+    /// Write a 'MultiBandBuffer<T>' into several bands.
+    /// # Arguments
+    /// * window - the window position from top left
+    /// * window_size - the window size (GDAL will interpolate data if window_size != buffer.size)
+    /// * bands - the indices of the bands to write, starting at 1
+    /// * buffer - the values to write, one band after another or pixel interleaved
+    pub fn write<T: GdalType + Copy>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        bands: &[isize],
+        buffer: &MultiBandBuffer<T>,
+    ) -> Result<()> {
+        if buffer.band_count != bands.len() {
+            return Err(ErrorKind::BandCountMismatch {
+                band_count: buffer.band_count,
+                expected: bands.len(),
+            }
+            .into());
+        }
+        check_window(window, window_size, self.raster_size())?;
+        check_buffer_len(buffer.data.len(), buffer.size, bands.len())?;
+        self._raster_io::<T>(
+            GDALRWFlag::GF_Write,
+            window,
+            window_size,
+            buffer.size,
+            bands,
+            buffer.interleave,
+            buffer.data.as_ptr() as *mut c_void,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn _raster_io<T: GdalType>(
+        &self,
+        rw_flag: GDALRWFlag::Type,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+        bands: &[isize],
+        interleave: Interleave,
+        c_buffer: *mut c_void,
+    ) -> Result<()> {
+        let mut c_bands = bands.iter().map(|&b| b as c_int).collect::<Vec<_>>();
+        let (pixel_space, line_space, band_space) = interleave.spacing::<T>(size, bands.len());
+        let rv = unsafe {
+            gdal_sys::GDALDatasetRasterIO(
+                self.c_dataset,
+                rw_flag,
+                window.0 as c_int,
+                window.1 as c_int,
+                window_size.0 as c_int,
+                window_size.1 as c_int,
+                c_buffer,
+                size.0 as c_int,
+                size.1 as c_int,
+                T::gdal_type(),
+                c_bands.len() as c_int,
+                c_bands.as_mut_ptr(),
+                pixel_space as c_int,
+                line_space as c_int,
+                band_space as c_int,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Append a new band of type `T` to the dataset and return it.
+    ///
+    /// Only some drivers support adding bands, most notably MEM and VRT. For
+    /// example a VRT band can be backed by a raw file with the `subClass=VRTRawRasterBand`
+    /// and `SourceFilename=...` options.
+    pub fn add_band<T: GdalType>(
+        &mut self,
+        options: &[RasterCreationOption],
+    ) -> Result<RasterBand> {
+        let c_options = _creation_options_list(options)?;
+        let rv = unsafe {
+            gdal_sys::GDALAddBand(self.c_dataset, T::gdal_type(), c_options.as_ptr() as _)
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        self.rasterband(self.raster_count())
+    }
+
+    /// Build raster overviews (pyramids).
+    ///
+    /// # Arguments
+    /// * resampling - the resampling method, one of `NEAREST`, `AVERAGE`, `GAUSS`, `CUBIC`,
+    ///   `CUBICSPLINE`, `LANCZOS`, `AVERAGE_MAGPHASE`, `MODE` or `NONE`
+    /// * levels - the decimation factors of the overviews, e.g. `&[2, 4, 8]`
+    /// * bands - the indices of the bands to build overviews for, or all bands if empty
+    pub fn build_overviews(
+        &mut self,
+        resampling: &str,
+        levels: &[i32],
+        bands: &[isize],
+    ) -> Result<()> {
+        self._build_overviews(resampling, levels, bands, None)
+    }
+
+    /// Like `build_overviews`, reporting the progress to `progress`.
+    ///
+    /// Building the overviews is cancelled if `progress` returns `ProgressStatus::Abort`.
+    pub fn build_overviews_with_progress(
+        &mut self,
+        resampling: &str,
+        levels: &[i32],
+        bands: &[isize],
+        progress: &mut ProgressFn,
+    ) -> Result<()> {
+        self._build_overviews(resampling, levels, bands, Some(progress))
+    }
+
+    fn _build_overviews(
+        &mut self,
+        resampling: &str,
+        levels: &[i32],
+        bands: &[isize],
+        mut progress: Option<&mut ProgressFn>,
+    ) -> Result<()> {
+        let c_resampling = CString::new(resampling)?;
+        let mut c_levels = levels.iter().map(|&l| l as c_int).collect::<Vec<_>>();
+        let mut c_bands = bands.iter().map(|&b| b as c_int).collect::<Vec<_>>();
+        let (c_progress, c_progress_arg) = _progress_args(progress.as_mut());
+        let rv = unsafe {
+            gdal_sys::GDALBuildOverviews(
+                self.c_dataset,
+                c_resampling.as_ptr(),
+                c_levels.len() as c_int,
+                c_levels.as_mut_ptr(),
+                c_bands.len() as c_int,
+                if c_bands.is_empty() {
+                    null_mut()
+                } else {
+                    c_bands.as_mut_ptr()
+                },
+                c_progress,
+                c_progress_arg,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
     fn child_layer(&self, c_layer: OGRLayerH) -> Layer {
         unsafe { Layer::from_c_layer(self, c_layer) }
     }
@@ -262,21 +673,89 @@ impl Dataset {
         Ok(self.child_layer(c_layer))
     }
 
+    /// Execute a SQL statement against the dataset.
+    ///
+    /// Returns the result set of `SELECT` statements, or `None` for statements
+    /// without any result set like `CREATE INDEX` or `DELETE`.
+    ///
+    /// # Arguments
+    /// * query - the SQL statement
+    /// * spatial_filter - only return features intersecting this geometry
+    /// * dialect - the SQL dialect of the statement
+    pub fn execute_sql(
+        &self,
+        query: &str,
+        spatial_filter: Option<&Geometry>,
+        dialect: SqlDialect,
+    ) -> Result<Option<ResultSet>> {
+        let c_query = CString::new(query)?;
+        let c_dialect = dialect.name().map(CString::new).transpose()?;
+        let c_spatial_filter = match spatial_filter {
+            Some(geometry) => unsafe { geometry.c_geometry() },
+            None => null_mut(),
+        };
+
+        unsafe { gdal_sys::CPLErrorReset() };
+        let c_layer = unsafe {
+            gdal_sys::GDALDatasetExecuteSQL(
+                self.c_dataset,
+                c_query.as_ptr(),
+                c_spatial_filter,
+                c_dialect.as_ref().map_or(ptr::null(), |d| d.as_ptr()),
+            )
+        };
+        if c_layer.is_null() {
+            // a statement without result set also returns NULL, but doesn't raise an error
+            let last_err = unsafe { gdal_sys::CPLGetLastErrorType() };
+            if last_err == CPLErr::CE_Failure || last_err == CPLErr::CE_Fatal {
+                return Err(_last_null_pointer_err("GDALDatasetExecuteSQL").into());
+            }
+            return Ok(None);
+        }
+        let layer = self.child_layer(c_layer);
+        Ok(Some(unsafe { ResultSet::new(self, layer) }))
+    }
+
+    /// Start a transaction on the dataset, e.g. to speed up bulk inserts into a GeoPackage.
+    ///
+    /// The returned `Transaction` dereferences to the dataset. Changes are applied
+    /// with `Transaction::commit`, and rolled back if the transaction is dropped
+    /// without being committed.
+    ///
+    /// Fails with `OGRERR_UNSUPPORTED_OPERATION` if the driver doesn't support
+    /// efficient transactions; see `start_forced_transaction` for an emulation.
+    pub fn start_transaction(&mut self) -> Result<Transaction> {
+        self._start_transaction(false)
+    }
+
+    /// Like `start_transaction`, but falls back to a slow emulation of transactions
+    /// for drivers that don't support them natively.
+    pub fn start_forced_transaction(&mut self) -> Result<Transaction> {
+        self._start_transaction(true)
+    }
+
+    fn _start_transaction(&mut self, force: bool) -> Result<Transaction> {
+        let rv = unsafe { gdal_sys::GDALDatasetStartTransaction(self.c_dataset, force as c_int) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(ErrorKind::OgrError {
+                err: rv,
+                method_name: "GDALDatasetStartTransaction",
+            }
+            .into());
+        }
+        Ok(Transaction {
+            dataset: self,
+            rollback_on_drop: true,
+        })
+    }
+
     /// Affine transformation called geotransformation.
     ///
     /// This is like a linear transformation preserves points, straight lines and planes.
     /// Also, sets of parallel lines remain parallel after an affine transformation.
     /// # Arguments
-    /// * transformation - coeficients of transformations
-    ///
-    /// x-coordinate of the top-left corner pixel (x-offset)
-    /// width of a pixel (x-resolution)
-    /// row rotation (typically zero)
-    /// y-coordinate of the top-left corner pixel
-    /// column rotation (typically zero)
-    /// height of a pixel (y-resolution, typically negative)
+    /// * transformation - coeficients of transformations, see `GeoTransform`
     pub fn set_geo_transform(&self, transformation: &GeoTransform) -> Result<()> {
-        assert_eq!(transformation.len(), 6);
         let rv = unsafe {
             gdal_sys::GDALSetGeoTransform(self.c_dataset, transformation.as_ptr() as *mut f64)
         };
@@ -286,14 +765,7 @@ impl Dataset {
         Ok(())
     }
 
-    /// Get affine transformation coefficients.
-    ///
-    /// x-coordinate of the top-left corner pixel (x-offset)
-    /// width of a pixel (x-resolution)
-    /// row rotation (typically zero)
-    /// y-coordinate of the top-left corner pixel
-    /// column rotation (typically zero)
-    /// height of a pixel (y-resolution, typically negative)
+    /// Get affine transformation coefficients, see `GeoTransform`.
     pub fn geo_transform(&self) -> Result<GeoTransform> {
         let mut transformation = GeoTransform::default();
         let rv =
@@ -307,6 +779,68 @@ impl Dataset {
     }
 }
 
+/// A transaction on a `Dataset`, started with `Dataset::start_transaction`.
+///
+/// The transaction is rolled back on drop, unless it was committed.
+#[must_use = "the transaction is rolled back when dropped, unless committed"]
+pub struct Transaction<'a> {
+    dataset: &'a mut Dataset,
+    rollback_on_drop: bool,
+}
+
+impl<'a> Transaction<'a> {
+    /// Commit the changes made during the transaction.
+    pub fn commit(mut self) -> Result<()> {
+        self.rollback_on_drop = false;
+        let rv = unsafe { gdal_sys::GDALDatasetCommitTransaction(self.dataset.c_dataset) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(ErrorKind::OgrError {
+                err: rv,
+                method_name: "GDALDatasetCommitTransaction",
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Discard the changes made during the transaction.
+    pub fn rollback(mut self) -> Result<()> {
+        self.rollback_on_drop = false;
+        let rv = unsafe { gdal_sys::GDALDatasetRollbackTransaction(self.dataset.c_dataset) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(ErrorKind::OgrError {
+                err: rv,
+                method_name: "GDALDatasetRollbackTransaction",
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Deref for Transaction<'a> {
+    type Target = Dataset;
+
+    fn deref(&self) -> &Dataset {
+        self.dataset
+    }
+}
+
+impl<'a> DerefMut for Transaction<'a> {
+    fn deref_mut(&mut self) -> &mut Dataset {
+        self.dataset
+    }
+}
+
+impl<'a> Drop for Transaction<'a> {
+    fn drop(&mut self) {
+        if self.rollback_on_drop {
+            // errors can't be reported from drop
+            unsafe { gdal_sys::GDALDatasetRollbackTransaction(self.dataset.c_dataset) };
+        }
+    }
+}
+
 impl MajorObject for Dataset {
     unsafe fn gdal_object_ptr(&self) -> GDALMajorObjectH {
         self.c_dataset
@@ -350,55 +884,63 @@ mod tests {
     fn test_open_ex_ro_vector() {
         Dataset::open_ex(
             fixture!("roads.geojson"),
-            Some(GDALAccess::GA_ReadOnly),
-            None,
-            None,
-            None,
+            DatasetOptions::new().flags(GdalOpenFlags::GDAL_OF_READONLY),
         )
         .unwrap();
     }
 
     #[test]
     fn test_open_ex_update_vector() {
-        Dataset::open_ex(
-            fixture!("roads.geojson"),
-            Some(GDALAccess::GA_Update),
-            None,
-            None,
-            None,
-        )
-        .unwrap();
+        Dataset::open_ex(fixture!("roads.geojson"), DatasetOptions::new().update()).unwrap();
     }
 
     #[test]
     fn test_open_ex_allowed_driver_vector() {
         Dataset::open_ex(
             fixture!("roads.geojson"),
-            None,
-            Some(&["GeoJSON"]),
-            None,
-            None,
+            DatasetOptions::new().allowed_drivers(&["GeoJSON"]),
         )
         .unwrap();
     }
 
     #[test]
     fn test_open_ex_allowed_driver_vector_fail() {
-        Dataset::open_ex(fixture!("roads.geojson"), None, Some(&["TIFF"]), None, None).unwrap_err();
+        Dataset::open_ex(
+            fixture!("roads.geojson"),
+            DatasetOptions::new().allowed_drivers(&["TIFF"]),
+        )
+        .unwrap_err();
     }
 
     #[test]
     fn test_open_ex_open_option() {
         Dataset::open_ex(
             fixture!("roads.geojson"),
-            None,
-            None,
-            Some(&["FLATTEN_NESTED_ATTRIBUTES=YES"]),
-            None,
+            DatasetOptions::new().open_options(&["FLATTEN_NESTED_ATTRIBUTES=YES"]),
         )
         .unwrap();
     }
 
+    #[test]
+    fn test_open_ex_vector_only() {
+        Dataset::open_ex(fixture!("roads.geojson"), DatasetOptions::new().vector()).unwrap();
+    }
+
+    #[test]
+    fn test_open_ex_raster_only_on_vector_fails() {
+        Dataset::open_ex(fixture!("roads.geojson"), DatasetOptions::new().raster()).unwrap_err();
+    }
+
+    #[test]
+    fn test_open_ex_shared() {
+        let options = DatasetOptions::new().vector().shared();
+        assert_eq!(
+            options.open_flags,
+            GdalOpenFlags::GDAL_OF_VECTOR | GdalOpenFlags::GDAL_OF_SHARED
+        );
+        Dataset::open_ex(fixture!("roads.geojson"), options).unwrap();
+    }
+
     #[test]
     fn test_layer_count() {
         let ds = Dataset::open(fixture!("roads.geojson")).unwrap();
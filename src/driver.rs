@@ -1,12 +1,12 @@
 use crate::dataset::Dataset;
 use crate::gdal_major_object::MajorObject;
 use crate::metadata::Metadata;
-use crate::raster::GdalType;
-use crate::utils::{_last_null_pointer_err, _string};
-use gdal_sys::{self, GDALDriverH, GDALMajorObjectH};
-use libc::c_int;
-use std::ffi::CString;
-use std::ptr::null_mut;
+use crate::raster::{GdalType, RasterCreationOption};
+use crate::utils::{_creation_options_list, _last_null_pointer_err, _opt_list_ptr, _string};
+use gdal_sys::{self, CPLXMLNode, CPLXMLNodeType, GDALDataType, GDALDriverH, GDALMajorObjectH};
+use libc::{c_char, c_int};
+use std::ffi::{CStr, CString};
+use std::ptr::null;
 use std::sync::Once;
 
 use crate::errors::*;
@@ -26,6 +26,33 @@ pub struct Driver {
     c_driver: GDALDriverH,
 }
 
+// Driver capabilities, as advertised by the `DCAP_*` metadata items.
+const DCAP_CREATE: &str = "DCAP_CREATE";
+const DCAP_CREATECOPY: &str = "DCAP_CREATECOPY";
+const DCAP_RASTER: &str = "DCAP_RASTER";
+const DCAP_VECTOR: &str = "DCAP_VECTOR";
+const DCAP_MULTIDIM_RASTER: &str = "DCAP_MULTIDIM_RASTER";
+const DCAP_VIRTUALIO: &str = "DCAP_VIRTUALIO";
+
+// Driver description items, as advertised by the `DMD_*` metadata items.
+const DMD_EXTENSION: &str = "DMD_EXTENSION";
+const DMD_EXTENSIONS: &str = "DMD_EXTENSIONS";
+
+/// A creation option supported by a driver, parsed from its
+/// `DMD_CREATIONOPTIONLIST` XML.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreationOptionDefn {
+    pub name: String,
+    /// The option type, e.g. `int`, `float`, `boolean`, `string` or `string-select`.
+    pub option_type: String,
+    pub description: Option<String>,
+    pub default: Option<String>,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    /// The allowed values of a `string-select` option.
+    pub values: Vec<String>,
+}
+
 impl Driver {
     pub fn get(name: &str) -> Result<Driver> {
         _register_drivers();
@@ -37,6 +64,30 @@ impl Driver {
         Ok(Driver { c_driver })
     }
 
+    /// Get the number of registered drivers.
+    pub fn count() -> isize {
+        _register_drivers();
+        (unsafe { gdal_sys::GDALGetDriverCount() }) as isize
+    }
+
+    /// Get a registered driver by its index, from 0 to `Driver::count() - 1`.
+    pub fn get_by_index(index: isize) -> Result<Driver> {
+        _register_drivers();
+        let c_driver = unsafe { gdal_sys::GDALGetDriver(index as c_int) };
+        if c_driver.is_null() {
+            return Err(_last_null_pointer_err("GDALGetDriver").into());
+        };
+        Ok(Driver { c_driver })
+    }
+
+    /// Iterate over all registered drivers.
+    pub fn all() -> DriverIterator {
+        DriverIterator {
+            index: 0,
+            count: Driver::count(),
+        }
+    }
+
     /// Creates a new Driver object by wrapping a C pointer
     ///
     /// # Safety
@@ -63,6 +114,100 @@ impl Driver {
         _string(rv)
     }
 
+    fn has_capability(&self, capability: &str) -> bool {
+        match self.metadata_item(capability, "") {
+            Some(value) => value.eq_ignore_ascii_case("YES"),
+            None => false,
+        }
+    }
+
+    /// Whether the driver supports `Driver::create`.
+    pub fn can_create(&self) -> bool {
+        self.has_capability(DCAP_CREATE)
+    }
+
+    /// Whether the driver supports `Dataset::create_copy`.
+    pub fn can_create_copy(&self) -> bool {
+        self.has_capability(DCAP_CREATECOPY)
+    }
+
+    /// Whether the driver handles raster data.
+    pub fn is_raster(&self) -> bool {
+        self.has_capability(DCAP_RASTER)
+    }
+
+    /// Whether the driver handles vector data.
+    pub fn is_vector(&self) -> bool {
+        self.has_capability(DCAP_VECTOR)
+    }
+
+    /// Whether the driver handles multidimensional raster data (GDAL >= 3.1).
+    pub fn is_multidim_raster(&self) -> bool {
+        self.has_capability(DCAP_MULTIDIM_RASTER)
+    }
+
+    /// Whether the driver can read and write through the `/vsi*` virtual file systems.
+    pub fn supports_virtual_io(&self) -> bool {
+        self.has_capability(DCAP_VIRTUALIO)
+    }
+
+    /// The file extensions handled by the driver, without the leading dot.
+    pub fn extensions(&self) -> Vec<String> {
+        self.metadata_item(DMD_EXTENSIONS, "")
+            .or_else(|| self.metadata_item(DMD_EXTENSION, ""))
+            .map(|extensions| {
+                extensions
+                    .split_whitespace()
+                    .map(|extension| extension.to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The creation options supported by the driver.
+    pub fn creation_options(&self) -> Result<Vec<CreationOptionDefn>> {
+        let c_xml = unsafe { gdal_sys::GDALGetDriverCreationOptionList(self.c_driver) };
+        if c_xml.is_null() || unsafe { *c_xml } == 0 {
+            return Ok(Vec::new());
+        }
+        let c_root = unsafe { gdal_sys::CPLParseXMLString(c_xml) };
+        if c_root.is_null() {
+            return Err(_last_null_pointer_err("CPLParseXMLString").into());
+        }
+        let mut options = Vec::new();
+        let mut c_option = unsafe { (*c_root).psChild };
+        while !c_option.is_null() {
+            if _xml_is_element(c_option, "Option") {
+                options.push(_creation_option_defn(c_option));
+            }
+            c_option = unsafe { (*c_option).psNext };
+        }
+        unsafe { gdal_sys::CPLDestroyXMLNode(c_root) };
+        Ok(options)
+    }
+
+    /// Check the creation options against the driver's `DMD_CREATIONOPTIONLIST`.
+    ///
+    /// Unknown options and values outside of the allowed range are reported as
+    /// `ErrorKind::InvalidCreationOptions`.
+    pub fn validate_creation_options(&self, options: &[RasterCreationOption]) -> Result<()> {
+        let c_options = _creation_options_list(options)?;
+        unsafe { gdal_sys::CPLErrorReset() };
+        let rv = unsafe {
+            gdal_sys::GDALValidateCreationOptions(self.c_driver, c_options.as_ptr() as _)
+        };
+        if rv == 0 {
+            let msg = _string(unsafe { gdal_sys::CPLGetLastErrorMsg() });
+            unsafe { gdal_sys::CPLErrorReset() };
+            return Err(ErrorKind::InvalidCreationOptions {
+                driver: self.short_name(),
+                msg,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
     pub fn create(
         &self,
         filename: &str,
@@ -79,8 +224,41 @@ impl Driver {
         size_x: isize,
         size_y: isize,
         bands: isize,
+    ) -> Result<Dataset> {
+        self.create_with_band_type_with_options::<T>(filename, size_x, size_y, bands, &[])
+    }
+
+    /// Create a new dataset, passing creation options such as
+    /// `TILED=YES` or `COMPRESS=DEFLATE` to the driver.
+    ///
+    /// The options are validated against the driver's creation option list first.
+    pub fn create_with_band_type_with_options<T: GdalType>(
+        &self,
+        filename: &str,
+        size_x: isize,
+        size_y: isize,
+        bands: isize,
+        options: &[RasterCreationOption],
+    ) -> Result<Dataset> {
+        self._create(filename, size_x, size_y, bands, T::gdal_type(), options)
+    }
+
+    pub(crate) fn _create(
+        &self,
+        filename: &str,
+        size_x: isize,
+        size_y: isize,
+        bands: isize,
+        data_type: GDALDataType::Type,
+        options: &[RasterCreationOption],
     ) -> Result<Dataset> {
         let c_filename = CString::new(filename)?;
+        let c_options = if options.is_empty() {
+            None
+        } else {
+            self.validate_creation_options(options)?;
+            Some(_creation_options_list(options)?)
+        };
         let c_dataset = unsafe {
             gdal_sys::GDALCreate(
                 self.c_driver,
@@ -88,8 +266,8 @@ impl Driver {
                 size_x as c_int,
                 size_y as c_int,
                 bands as c_int,
-                T::gdal_type(),
-                null_mut(),
+                data_type,
+                _opt_list_ptr(&c_options),
             )
         };
         if c_dataset.is_null() {
@@ -103,6 +281,65 @@ impl Driver {
     }
 }
 
+pub struct DriverIterator {
+    index: isize,
+    count: isize,
+}
+
+impl Iterator for DriverIterator {
+    type Item = Driver;
+
+    #[inline]
+    fn next(&mut self) -> Option<Driver> {
+        // skip indices that fail to resolve instead of ending the iteration early
+        while self.index < self.count {
+            let driver = Driver::get_by_index(self.index);
+            self.index += 1;
+            if let Ok(driver) = driver {
+                return Some(driver);
+            }
+        }
+        None
+    }
+}
+
+fn _xml_is_element(c_node: *mut CPLXMLNode, name: &str) -> bool {
+    let node = unsafe { &*c_node };
+    node.eType == CPLXMLNodeType::CXT_Element
+        && unsafe { CStr::from_ptr(node.pszValue) }.to_bytes() == name.as_bytes()
+}
+
+fn _xml_value(c_node: *mut CPLXMLNode, path: &str) -> Option<String> {
+    let c_path = CString::new(path).ok()?;
+    let c_value: *const c_char =
+        unsafe { gdal_sys::CPLGetXMLValue(c_node as _, c_path.as_ptr(), null()) };
+    if c_value.is_null() {
+        None
+    } else {
+        Some(_string(c_value))
+    }
+}
+
+fn _creation_option_defn(c_option: *mut CPLXMLNode) -> CreationOptionDefn {
+    let mut values = Vec::new();
+    let mut c_child = unsafe { (*c_option).psChild };
+    while !c_child.is_null() {
+        if _xml_is_element(c_child, "Value") {
+            values.extend(_xml_value(c_child, ""));
+        }
+        c_child = unsafe { (*c_child).psNext };
+    }
+    CreationOptionDefn {
+        name: _xml_value(c_option, "name").unwrap_or_default(),
+        option_type: _xml_value(c_option, "type").unwrap_or_default(),
+        description: _xml_value(c_option, "description"),
+        default: _xml_value(c_option, "default"),
+        min: _xml_value(c_option, "min"),
+        max: _xml_value(c_option, "max"),
+        values,
+    }
+}
+
 impl MajorObject for Driver {
     unsafe fn gdal_object_ptr(&self) -> GDALMajorObjectH {
         self.c_driver
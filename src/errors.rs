@@ -2,7 +2,7 @@ use libc::c_int;
 use std::{self, fmt, result};
 
 use failure::{Backtrace, Context, Fail};
-use gdal_sys::{CPLErr, OGRErr, OGRFieldType};
+use gdal_sys::{CPLErr, GDALDataType, OGRErr, OGRFieldType};
 
 pub type Result<T> = result::Result<T, Error>;
 
@@ -70,6 +70,8 @@ pub enum ErrorKind {
     },
     #[fail(display = "Unlinked Geometry on method {}", method_name)]
     UnlinkedGeometry { method_name: &'static str },
+    #[fail(display = "Invalid creation options for driver '{}': {}", driver, msg)]
+    InvalidCreationOptions { driver: String, msg: String },
     #[fail(
         display = "Invalid coordinate range while transforming points from {} to {}: {:?}",
         from, to, msg
@@ -79,6 +81,51 @@ pub enum ErrorKind {
         to: String,
         msg: Option<String>,
     },
+    #[fail(
+        display = "Block index {:?} is out of range, the band has {:?} blocks",
+        block_index, block_count
+    )]
+    InvalidBlockIndex {
+        block_index: (usize, usize),
+        block_count: (usize, usize),
+    },
+    #[fail(
+        display = "Buffer size {:?} does not match the expected size {:?}",
+        size, expected
+    )]
+    BufferSizeMismatch {
+        size: (usize, usize),
+        expected: (usize, usize),
+    },
+    #[fail(
+        display = "Window at {:?} with size {:?} is outside of the raster of size {:?}",
+        window, window_size, raster_size
+    )]
+    InvalidWindow {
+        window: (isize, isize),
+        window_size: (usize, usize),
+        raster_size: (usize, usize),
+    },
+    #[fail(display = "Buffer has {} elements, but {} are required", len, expected)]
+    BufferLengthMismatch { len: usize, expected: usize },
+    #[fail(
+        display = "Buffer has {} bands, but {} bands are accessed",
+        band_count, expected
+    )]
+    BandCountMismatch { band_count: usize, expected: usize },
+    #[fail(
+        display = "Got {} burn values, but one per geometry or layer ({}) or one per geometry or layer and band ({}) is required",
+        count, item_count, per_band_count
+    )]
+    InvalidBurnValueCount {
+        count: usize,
+        item_count: usize,
+        per_band_count: usize,
+    },
+    #[fail(display = "Invalid warp options: {}", msg)]
+    InvalidWarpOptions { msg: String },
+    #[fail(display = "Unsupported raster data type {}", data_type)]
+    UnsupportedDataType { data_type: GDALDataType::Type },
 }
 
 impl Fail for Error {
@@ -0,0 +1,180 @@
+use std::ops::Index;
+
+/// An affine transformation from pixel/line to georeferenced coordinates.
+///
+/// The six coefficients are, in GDAL's order:
+///
+/// * x-coordinate of the top-left corner of the top-left pixel (x-offset)
+/// * width of a pixel (x-resolution)
+/// * row rotation (typically zero)
+/// * y-coordinate of the top-left corner of the top-left pixel (y-offset)
+/// * column rotation (typically zero)
+/// * height of a pixel (y-resolution, typically negative)
+///
+/// ```
+/// use gdal::GeoTransform;
+///
+/// let transform = GeoTransform::new([10., 0.5, 0., 50., 0., -0.25]);
+/// assert_eq!(transform.apply(2., 4.), (11., 49.));
+/// let inverse = transform.invert().unwrap();
+/// assert_eq!(inverse.apply(11., 49.), (2., 4.));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoTransform([f64; 6]);
+
+impl Default for GeoTransform {
+    /// The identity transformation.
+    fn default() -> Self {
+        GeoTransform([0., 1., 0., 0., 0., 1.])
+    }
+}
+
+impl GeoTransform {
+    pub fn new(coefficients: [f64; 6]) -> Self {
+        GeoTransform(coefficients)
+    }
+
+    /// The six coefficients in GDAL's order.
+    pub fn coefficients(&self) -> [f64; 6] {
+        self.0
+    }
+
+    pub fn x_origin(&self) -> f64 {
+        self.0[0]
+    }
+
+    pub fn pixel_width(&self) -> f64 {
+        self.0[1]
+    }
+
+    pub fn row_rotation(&self) -> f64 {
+        self.0[2]
+    }
+
+    pub fn y_origin(&self) -> f64 {
+        self.0[3]
+    }
+
+    pub fn column_rotation(&self) -> f64 {
+        self.0[4]
+    }
+
+    /// The height of a pixel, typically negative for north-up images.
+    pub fn pixel_height(&self) -> f64 {
+        self.0[5]
+    }
+
+    /// The transformation from georeferenced to pixel/line coordinates, or `None` if
+    /// this transformation is not invertible.
+    pub fn invert(&self) -> Option<GeoTransform> {
+        let mut coefficients = self.0;
+        let mut inverse = [0.; 6];
+        let rv = unsafe {
+            gdal_sys::GDALInvGeoTransform(coefficients.as_mut_ptr(), inverse.as_mut_ptr())
+        };
+        if rv == 0 {
+            return None;
+        }
+        Some(GeoTransform(inverse))
+    }
+
+    /// Transform pixel/line coordinates to georeferenced coordinates.
+    pub fn apply(&self, pixel: f64, line: f64) -> (f64, f64) {
+        let gt = &self.0;
+        (
+            gt[0] + pixel * gt[1] + line * gt[2],
+            gt[3] + pixel * gt[4] + line * gt[5],
+        )
+    }
+
+    /// The georeferenced extent of a raster of `size` pixels and lines, as
+    /// `(min_x, min_y, max_x, max_y)`.
+    pub fn bounds(&self, size: (usize, usize)) -> (f64, f64, f64, f64) {
+        let (size_x, size_y) = (size.0 as f64, size.1 as f64);
+        _extent(&[
+            self.apply(0., 0.),
+            self.apply(size_x, 0.),
+            self.apply(0., size_y),
+            self.apply(size_x, size_y),
+        ])
+    }
+
+    /// The window, as offset and size in pixels, that covers the georeferenced bounding
+    /// box `(min_x, min_y, max_x, max_y)`. The window is not clipped to any raster size.
+    ///
+    /// Returns `None` if this transformation is not invertible.
+    pub fn window(&self, bbox: (f64, f64, f64, f64)) -> Option<((isize, isize), (usize, usize))> {
+        let inverse = self.invert()?;
+        let (min_x, min_y, max_x, max_y) = bbox;
+        let (min_pixel, min_line, max_pixel, max_line) = _extent(&[
+            inverse.apply(min_x, min_y),
+            inverse.apply(max_x, min_y),
+            inverse.apply(min_x, max_y),
+            inverse.apply(max_x, max_y),
+        ]);
+        let (offset_x, offset_y) = (min_pixel.floor(), min_line.floor());
+        Some((
+            (offset_x as isize, offset_y as isize),
+            (
+                (max_pixel.ceil() - offset_x) as usize,
+                (max_line.ceil() - offset_y) as usize,
+            ),
+        ))
+    }
+
+    /// The transformation equivalent to applying this one, then `other`.
+    pub fn compose(&self, other: &GeoTransform) -> GeoTransform {
+        let mut composed = [0.; 6];
+        unsafe {
+            gdal_sys::GDALComposeGeoTransforms(
+                self.0.as_ptr(),
+                other.0.as_ptr(),
+                composed.as_mut_ptr(),
+            )
+        };
+        GeoTransform(composed)
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const f64 {
+        self.0.as_ptr()
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut f64 {
+        self.0.as_mut_ptr()
+    }
+}
+
+/// The `(min_x, min_y, max_x, max_y)` extent of `points`.
+fn _extent(points: &[(f64, f64)]) -> (f64, f64, f64, f64) {
+    points.iter().fold(
+        (
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ),
+        |(min_x, min_y, max_x, max_y), &(x, y)| {
+            (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+        },
+    )
+}
+
+impl From<[f64; 6]> for GeoTransform {
+    fn from(coefficients: [f64; 6]) -> Self {
+        GeoTransform(coefficients)
+    }
+}
+
+impl From<GeoTransform> for [f64; 6] {
+    fn from(geo_transform: GeoTransform) -> Self {
+        geo_transform.0
+    }
+}
+
+impl Index<usize> for GeoTransform {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        &self.0[index]
+    }
+}
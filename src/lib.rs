@@ -27,16 +27,20 @@ mod dataset;
 mod driver;
 pub mod errors;
 mod gdal_major_object;
+mod geo_transform;
 mod metadata;
+mod progress;
 pub mod raster;
 pub mod spatial_ref;
 mod utils;
 pub mod vector;
 pub mod version;
 
-pub use dataset::Dataset;
-pub use driver::Driver;
+pub use dataset::{Dataset, DatasetOptions, GdalOpenFlags, Transaction};
+pub use driver::{CreationOptionDefn, Driver, DriverIterator};
+pub use geo_transform::GeoTransform;
 pub use metadata::Metadata;
+pub use progress::{ProgressFn, ProgressStatus};
 
 #[cfg(test)]
 fn assert_almost_eq(a: f64, b: f64) {
@@ -13,6 +13,12 @@ pub trait Metadata: MajorObject {
         Ok(_string(c_res))
     }
 
+    fn set_description(&self, description: &str) -> Result<()> {
+        let c_description = CString::new(description)?;
+        unsafe { gdal_sys::GDALSetDescription(self.gdal_object_ptr(), c_description.as_ptr()) };
+        Ok(())
+    }
+
     fn metadata_item(&self, key: &str, domain: &str) -> Option<String> {
         if let Ok(c_key) = CString::new(key.to_owned()) {
             if let Ok(c_domain) = CString::new(domain.to_owned()) {
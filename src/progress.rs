@@ -0,0 +1,67 @@
+//! Progress reporting for long running GDAL operations.
+//!
+//! ```
+//! use std::path::Path;
+//! use gdal::{Dataset, Driver, ProgressStatus};
+//!
+//! let dataset = Dataset::open(Path::new("fixtures/tinymarble.png")).unwrap();
+//! let driver = Driver::get("MEM").unwrap();
+//! let mut report = |complete: f64, _message: &str| {
+//!     println!("{:.0}%", complete * 100.0);
+//!     ProgressStatus::Continue
+//! };
+//! let copy = dataset
+//!     .create_copy_with_progress(&driver, "", &[], &mut report)
+//!     .unwrap();
+//! ```
+
+use gdal_sys::GDALProgressFunc;
+use libc::{c_char, c_double, c_int, c_void};
+use std::ffi::CStr;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+/// Returned by a progress callback to tell GDAL whether to go on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgressStatus {
+    Continue,
+    Abort,
+}
+
+/// A progress callback, called with the completed fraction (from 0.0 to 1.0)
+/// and an optional message from GDAL.
+///
+/// Returning `ProgressStatus::Abort` cancels the operation, which then fails
+/// with a "User terminated" CPL error.
+pub type ProgressFn<'a> = dyn FnMut(f64, &str) -> ProgressStatus + 'a;
+
+unsafe extern "C" fn _progress_trampoline(
+    complete: c_double,
+    message: *const c_char,
+    arg: *mut c_void,
+) -> c_int {
+    let progress = &mut *(arg as *mut &mut ProgressFn);
+    let message = if message.is_null() {
+        "".into()
+    } else {
+        CStr::from_ptr(message).to_string_lossy()
+    };
+    // unwinding into GDAL is undefined behavior, so a panic aborts the operation instead
+    match catch_unwind(AssertUnwindSafe(|| progress(complete, &message))) {
+        Ok(ProgressStatus::Continue) => 1,
+        Ok(ProgressStatus::Abort) | Err(_) => 0,
+    }
+}
+
+/// Returns the progress function and argument to pass to GDAL.
+///
+/// `progress` must outlive the GDAL call.
+pub fn _progress_args(progress: Option<&mut &mut ProgressFn>) -> (GDALProgressFunc, *mut c_void) {
+    match progress {
+        Some(progress) => (
+            Some(_progress_trampoline),
+            progress as *mut &mut ProgressFn as *mut c_void,
+        ),
+        None => (None, ptr::null_mut()),
+    }
+}
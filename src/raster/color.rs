@@ -0,0 +1,285 @@
+use crate::utils::{_last_null_pointer_err, _string};
+use gdal_sys::{self, GDALColorEntry, GDALColorInterp, GDALColorTableH, GDALPaletteInterp};
+use libc::c_int;
+use std::ffi::CString;
+
+use crate::errors::*;
+
+/// The color interpretation of a raster band, e.g. whether it holds the red channel
+/// of an RGB image or indices into a color table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorInterpretation {
+    Undefined,
+    GrayIndex,
+    PaletteIndex,
+    RedBand,
+    GreenBand,
+    BlueBand,
+    AlphaBand,
+    HueBand,
+    SaturationBand,
+    LightnessBand,
+    CyanBand,
+    MagentaBand,
+    YellowBand,
+    BlackBand,
+    YCbCrSpaceYBand,
+    YCbCrSpaceCbBand,
+    YCbCrSpaceCrBand,
+}
+
+impl ColorInterpretation {
+    /// Convert from the GDAL value. Unknown values map to `Undefined`.
+    pub fn from_gdal(color_interp: GDALColorInterp::Type) -> Self {
+        match color_interp {
+            GDALColorInterp::GCI_GrayIndex => ColorInterpretation::GrayIndex,
+            GDALColorInterp::GCI_PaletteIndex => ColorInterpretation::PaletteIndex,
+            GDALColorInterp::GCI_RedBand => ColorInterpretation::RedBand,
+            GDALColorInterp::GCI_GreenBand => ColorInterpretation::GreenBand,
+            GDALColorInterp::GCI_BlueBand => ColorInterpretation::BlueBand,
+            GDALColorInterp::GCI_AlphaBand => ColorInterpretation::AlphaBand,
+            GDALColorInterp::GCI_HueBand => ColorInterpretation::HueBand,
+            GDALColorInterp::GCI_SaturationBand => ColorInterpretation::SaturationBand,
+            GDALColorInterp::GCI_LightnessBand => ColorInterpretation::LightnessBand,
+            GDALColorInterp::GCI_CyanBand => ColorInterpretation::CyanBand,
+            GDALColorInterp::GCI_MagentaBand => ColorInterpretation::MagentaBand,
+            GDALColorInterp::GCI_YellowBand => ColorInterpretation::YellowBand,
+            GDALColorInterp::GCI_BlackBand => ColorInterpretation::BlackBand,
+            GDALColorInterp::GCI_YCbCr_YBand => ColorInterpretation::YCbCrSpaceYBand,
+            GDALColorInterp::GCI_YCbCr_CbBand => ColorInterpretation::YCbCrSpaceCbBand,
+            GDALColorInterp::GCI_YCbCr_CrBand => ColorInterpretation::YCbCrSpaceCrBand,
+            _ => ColorInterpretation::Undefined,
+        }
+    }
+
+    pub fn to_gdal(self) -> GDALColorInterp::Type {
+        match self {
+            ColorInterpretation::Undefined => GDALColorInterp::GCI_Undefined,
+            ColorInterpretation::GrayIndex => GDALColorInterp::GCI_GrayIndex,
+            ColorInterpretation::PaletteIndex => GDALColorInterp::GCI_PaletteIndex,
+            ColorInterpretation::RedBand => GDALColorInterp::GCI_RedBand,
+            ColorInterpretation::GreenBand => GDALColorInterp::GCI_GreenBand,
+            ColorInterpretation::BlueBand => GDALColorInterp::GCI_BlueBand,
+            ColorInterpretation::AlphaBand => GDALColorInterp::GCI_AlphaBand,
+            ColorInterpretation::HueBand => GDALColorInterp::GCI_HueBand,
+            ColorInterpretation::SaturationBand => GDALColorInterp::GCI_SaturationBand,
+            ColorInterpretation::LightnessBand => GDALColorInterp::GCI_LightnessBand,
+            ColorInterpretation::CyanBand => GDALColorInterp::GCI_CyanBand,
+            ColorInterpretation::MagentaBand => GDALColorInterp::GCI_MagentaBand,
+            ColorInterpretation::YellowBand => GDALColorInterp::GCI_YellowBand,
+            ColorInterpretation::BlackBand => GDALColorInterp::GCI_BlackBand,
+            ColorInterpretation::YCbCrSpaceYBand => GDALColorInterp::GCI_YCbCr_YBand,
+            ColorInterpretation::YCbCrSpaceCbBand => GDALColorInterp::GCI_YCbCr_CbBand,
+            ColorInterpretation::YCbCrSpaceCrBand => GDALColorInterp::GCI_YCbCr_CrBand,
+        }
+    }
+
+    /// Look up a color interpretation by its GDAL name, e.g. `"Red"` or `"Palette"`.
+    pub fn from_name(name: &str) -> Result<Self> {
+        let c_name = CString::new(name)?;
+        let rv = unsafe { gdal_sys::GDALGetColorInterpretationByName(c_name.as_ptr()) };
+        Ok(ColorInterpretation::from_gdal(rv))
+    }
+
+    /// The GDAL name of this color interpretation, e.g. `"Red"` or `"Palette"`.
+    pub fn name(self) -> String {
+        _string(unsafe { gdal_sys::GDALGetColorInterpretationName(self.to_gdal()) })
+    }
+}
+
+/// How the entries of a `ColorTable` are to be interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteInterpretation {
+    /// Grayscale, only `c1` is used.
+    Gray,
+    /// Red, green, blue and alpha in `c1` to `c4`.
+    Rgba,
+    /// Cyan, magenta, yellow and black in `c1` to `c4`.
+    Cmyk,
+    /// Hue, lightness and saturation in `c1` to `c3`.
+    Hls,
+}
+
+impl PaletteInterpretation {
+    fn from_gdal(palette_interp: GDALPaletteInterp::Type) -> Self {
+        match palette_interp {
+            GDALPaletteInterp::GPI_Gray => PaletteInterpretation::Gray,
+            GDALPaletteInterp::GPI_CMYK => PaletteInterpretation::Cmyk,
+            GDALPaletteInterp::GPI_HLS => PaletteInterpretation::Hls,
+            _ => PaletteInterpretation::Rgba,
+        }
+    }
+
+    fn to_gdal(self) -> GDALPaletteInterp::Type {
+        match self {
+            PaletteInterpretation::Gray => GDALPaletteInterp::GPI_Gray,
+            PaletteInterpretation::Rgba => GDALPaletteInterp::GPI_RGB,
+            PaletteInterpretation::Cmyk => GDALPaletteInterp::GPI_CMYK,
+            PaletteInterpretation::Hls => GDALPaletteInterp::GPI_HLS,
+        }
+    }
+}
+
+/// A single color table entry. The meaning of the components depends on the
+/// `PaletteInterpretation` of the table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ColorEntry {
+    pub c1: i16,
+    pub c2: i16,
+    pub c3: i16,
+    pub c4: i16,
+}
+
+impl ColorEntry {
+    /// Create an RGBA entry.
+    pub fn rgba(r: i16, g: i16, b: i16, a: i16) -> Self {
+        ColorEntry {
+            c1: r,
+            c2: g,
+            c3: b,
+            c4: a,
+        }
+    }
+
+    fn from_c_entry(entry: &GDALColorEntry) -> Self {
+        ColorEntry {
+            c1: entry.c1,
+            c2: entry.c2,
+            c3: entry.c3,
+            c4: entry.c4,
+        }
+    }
+
+    fn to_c_entry(self) -> GDALColorEntry {
+        GDALColorEntry {
+            c1: self.c1,
+            c2: self.c2,
+            c3: self.c3,
+            c4: self.c4,
+        }
+    }
+}
+
+/// An owned color table (palette), as used by paletted raster bands.
+#[derive(Debug)]
+pub struct ColorTable {
+    c_color_table: GDALColorTableH,
+}
+
+impl ColorTable {
+    /// Create an empty color table.
+    pub fn new(palette_interpretation: PaletteInterpretation) -> Result<Self> {
+        let c_color_table =
+            unsafe { gdal_sys::GDALCreateColorTable(palette_interpretation.to_gdal()) };
+        if c_color_table.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateColorTable").into());
+        }
+        Ok(ColorTable { c_color_table })
+    }
+
+    /// Create an RGBA color table, linearly interpolating between `start` at
+    /// `start_index` and `end` at `end_index`.
+    pub fn color_ramp(
+        start_index: usize,
+        start: &ColorEntry,
+        end_index: usize,
+        end: &ColorEntry,
+    ) -> Result<Self> {
+        let mut color_table = ColorTable::new(PaletteInterpretation::Rgba)?;
+        color_table.create_ramp(start_index, start, end_index, end);
+        Ok(color_table)
+    }
+
+    /// Create a copy of a color table owned by GDAL.
+    ///
+    /// # Safety
+    /// The caller must ensure that `c_color_table` is a valid color table handle.
+    pub unsafe fn from_c_color_table(c_color_table: GDALColorTableH) -> Result<Self> {
+        let c_color_table = gdal_sys::GDALCloneColorTable(c_color_table);
+        if c_color_table.is_null() {
+            return Err(_last_null_pointer_err("GDALCloneColorTable").into());
+        }
+        Ok(ColorTable { c_color_table })
+    }
+
+    /// Create a copy of this color table.
+    pub fn try_clone(&self) -> Result<Self> {
+        unsafe { ColorTable::from_c_color_table(self.c_color_table) }
+    }
+
+    pub fn c_color_table(&self) -> GDALColorTableH {
+        self.c_color_table
+    }
+
+    pub fn palette_interpretation(&self) -> PaletteInterpretation {
+        PaletteInterpretation::from_gdal(unsafe {
+            gdal_sys::GDALGetPaletteInterpretation(self.c_color_table)
+        })
+    }
+
+    /// The number of entries in the table.
+    pub fn entry_count(&self) -> usize {
+        (unsafe { gdal_sys::GDALGetColorEntryCount(self.c_color_table) }) as usize
+    }
+
+    /// Get the entry at `index`, or `None` if it is out of range.
+    pub fn entry(&self, index: usize) -> Option<ColorEntry> {
+        let c_entry = unsafe { gdal_sys::GDALGetColorEntry(self.c_color_table, index as c_int) };
+        if c_entry.is_null() {
+            return None;
+        }
+        Some(ColorEntry::from_c_entry(unsafe { &*c_entry }))
+    }
+
+    /// Get the entry at `index` translated to RGBA, or `None` if it is out of range
+    /// or cannot be translated.
+    pub fn entry_as_rgba(&self, index: usize) -> Option<ColorEntry> {
+        let mut c_entry = ColorEntry::default().to_c_entry();
+        let rv = unsafe {
+            gdal_sys::GDALGetColorEntryAsRGB(self.c_color_table, index as c_int, &mut c_entry)
+        };
+        if rv == 0 {
+            return None;
+        }
+        Some(ColorEntry::from_c_entry(&c_entry))
+    }
+
+    /// Iterate over all entries of the table.
+    pub fn entries(&self) -> impl Iterator<Item = ColorEntry> + '_ {
+        (0..self.entry_count()).filter_map(move |index| self.entry(index))
+    }
+
+    /// Set the entry at `index`, growing the table if needed.
+    pub fn set_entry(&mut self, index: usize, entry: &ColorEntry) {
+        let c_entry = entry.to_c_entry();
+        unsafe { gdal_sys::GDALSetColorEntry(self.c_color_table, index as c_int, &c_entry) };
+    }
+
+    /// Fill the entries from `start_index` to `end_index` with a linear ramp from
+    /// `start` to `end`, growing the table if needed.
+    pub fn create_ramp(
+        &mut self,
+        start_index: usize,
+        start: &ColorEntry,
+        end_index: usize,
+        end: &ColorEntry,
+    ) {
+        let c_start = start.to_c_entry();
+        let c_end = end.to_c_entry();
+        unsafe {
+            gdal_sys::GDALCreateColorRamp(
+                self.c_color_table,
+                start_index as c_int,
+                &c_start,
+                end_index as c_int,
+                &c_end,
+            )
+        };
+    }
+}
+
+impl Drop for ColorTable {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALDestroyColorTable(self.c_color_table) };
+    }
+}
@@ -0,0 +1,102 @@
+use crate::errors::*;
+use crate::utils::_string;
+use crate::GeoTransform;
+use gdal_sys::{self, GDAL_GCP};
+use libc::c_int;
+use std::ffi::CString;
+
+/// A ground control point, tying a pixel/line position of the raster to a
+/// georeferenced position.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gcp {
+    /// Unique identifier, often numeric
+    pub id: String,
+    /// Informational message or ""
+    pub info: String,
+    /// Pixel (x) location of the GCP on the raster
+    pub pixel: f64,
+    /// Line (y) location of the GCP on the raster
+    pub line: f64,
+    /// X position of the GCP in georeferenced space
+    pub x: f64,
+    /// Y position of the GCP in georeferenced space
+    pub y: f64,
+    /// Elevation of the GCP, or zero if not known
+    pub z: f64,
+}
+
+impl Gcp {
+    pub(crate) fn from_c_gcp(c_gcp: &GDAL_GCP) -> Gcp {
+        Gcp {
+            id: _string(c_gcp.pszId),
+            info: _string(c_gcp.pszInfo),
+            pixel: c_gcp.dfGCPPixel,
+            line: c_gcp.dfGCPLine,
+            x: c_gcp.dfGCPX,
+            y: c_gcp.dfGCPY,
+            z: c_gcp.dfGCPZ,
+        }
+    }
+}
+
+/// The `GDAL_GCP` values of some `Gcp`s, along with the strings they point to.
+pub(crate) struct CGcpList {
+    _strings: Vec<CString>,
+    c_gcps: Vec<GDAL_GCP>,
+}
+
+impl CGcpList {
+    pub fn new(gcps: &[Gcp]) -> Result<CGcpList> {
+        let mut strings = Vec::with_capacity(gcps.len() * 2);
+        let mut c_gcps = Vec::with_capacity(gcps.len());
+        for gcp in gcps {
+            let c_id = CString::new(gcp.id.as_str())?;
+            let c_info = CString::new(gcp.info.as_str())?;
+            c_gcps.push(GDAL_GCP {
+                pszId: c_id.as_ptr() as *mut _,
+                pszInfo: c_info.as_ptr() as *mut _,
+                dfGCPPixel: gcp.pixel,
+                dfGCPLine: gcp.line,
+                dfGCPX: gcp.x,
+                dfGCPY: gcp.y,
+                dfGCPZ: gcp.z,
+            });
+            strings.push(c_id);
+            strings.push(c_info);
+        }
+        Ok(CGcpList {
+            _strings: strings,
+            c_gcps,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.c_gcps.len()
+    }
+
+    pub fn as_ptr(&self) -> *const GDAL_GCP {
+        self.c_gcps.as_ptr()
+    }
+}
+
+/// Fit an affine `GeoTransform` through ground control points.
+///
+/// With `approx_ok` set to `false`, `None` is returned if any GCP is more than
+/// a quarter pixel away from the fitted transform. `None` is also returned if
+/// there are less than two GCPs, or if three or more GCPs are colinear.
+pub fn gcps_to_geo_transform(gcps: &[Gcp], approx_ok: bool) -> Result<Option<GeoTransform>> {
+    let c_gcps = CGcpList::new(gcps)?;
+    let mut transformation = GeoTransform::default();
+    let rv = unsafe {
+        gdal_sys::GDALGCPsToGeoTransform(
+            c_gcps.len() as c_int,
+            c_gcps.as_ptr(),
+            transformation.as_mut_ptr(),
+            approx_ok as c_int,
+        )
+    };
+    if rv == 0 {
+        return Ok(None);
+    }
+    Ok(Some(transformation))
+}
@@ -1,12 +1,31 @@
 //! GDAL Raster Data
 
+mod color;
+mod gcp;
 mod rasterband;
+mod rasterize;
+mod rat;
+mod transformer;
 mod types;
 mod warp;
 
-pub use rasterband::{Buffer, ByteBuffer, RasterBand};
-pub use types::{GDALDataType, GdalType};
-pub use warp::reproject;
+pub use color::{ColorEntry, ColorInterpretation, ColorTable, PaletteInterpretation};
+pub(crate) use gcp::CGcpList;
+pub use gcp::{gcps_to_geo_transform, Gcp};
+pub(crate) use rasterband::{check_buffer_len, check_window};
+pub use rasterband::{
+    BlockIterator, Buffer, ByteBuffer, DynBuffer, GdalMaskFlags, Histogram, Interleave,
+    MultiBandBuffer, RasterBand, RasterIOExtraArg, ResampleAlg, Statistics,
+};
+pub use rasterize::{rasterize, rasterize_layers, BurnSource, MergeAlgorithm, RasterizeOptions};
+pub use rat::{RasterAttributeTable, RatColumn, RatFieldType, RatFieldUsage};
+pub use transformer::Transformer;
+pub use types::{GDALDataType, GdalDataTypeExt, GdalType, RasterCreationOption};
+pub use warp::{
+    auto_create_warped_vrt, create_warped, create_warped_vrt, reproject, reproject_with_progress,
+    suggested_warp_output, warp, warp_with_progress, SuggestedWarpOutput, WarpOptions,
+    WarpResampleAlg, WarpedVrt,
+};
 
 #[cfg(test)]
 mod tests;
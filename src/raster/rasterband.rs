@@ -3,16 +3,42 @@ use std::marker::PhantomData;
 use crate::dataset::Dataset;
 use crate::gdal_major_object::MajorObject;
 use crate::metadata::Metadata;
-use crate::raster::{GDALDataType, GdalType};
-use crate::utils::_last_cpl_err;
-use gdal_sys::{self, CPLErr, GDALMajorObjectH, GDALRWFlag, GDALRasterBandH};
-use libc::c_int;
+use crate::raster::{
+    ColorInterpretation, ColorTable, GDALDataType, GdalType, RasterAttributeTable,
+};
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string, _string_array, CStringList};
+use bitflags::bitflags;
+use gdal_sys::{
+    self, CPLErr, GDALMajorObjectH, GDALRIOResampleAlg, GDALRWFlag, GDALRasterBandH,
+    GDALRasterIOExtraArg, GUIntBig,
+};
+use libc::{c_int, c_void};
+use std::ffi::CString;
+use std::ptr;
 
 #[cfg(feature = "ndarray")]
-use ndarray::Array2;
+use ndarray::{Array2, ArrayView2};
+
+#[cfg(feature = "complex")]
+use num_complex::Complex;
 
 use crate::errors::*;
 
+bitflags! {
+    /// Flags describing the mask band of a raster band, as returned by
+    /// `RasterBand::mask_flags`. They mirror GDAL's `GMF_*` constants.
+    pub struct GdalMaskFlags: c_int {
+        /// All pixels are valid; the mask band is all 255.
+        const GMF_ALL_VALID = 0x01;
+        /// The mask band is shared between all bands of the dataset.
+        const GMF_PER_DATASET = 0x02;
+        /// The mask band is derived from an alpha band.
+        const GMF_ALPHA = 0x04;
+        /// The mask band is derived from the nodata value.
+        const GMF_NODATA = 0x08;
+    }
+}
+
 /// Represents a single band of a dataset.
 ///
 /// This object carries the lifetime of the dataset that
@@ -81,8 +107,8 @@ impl<'a> RasterBand<'a> {
         size: (usize, usize),
         buffer: &mut [T],
     ) -> Result<()> {
-        let pixels = (size.0 * size.1) as usize;
-        assert_eq!(buffer.len(), pixels);
+        check_window(window, window_size, self.size())?;
+        check_buffer_len(buffer.len(), size, 1)?;
 
         //let no_data:
         let rv = unsafe {
@@ -108,6 +134,70 @@ impl<'a> RasterBand<'a> {
         Ok(())
     }
 
+    /// Read data from this band into a slice, using `GDALRasterIOEx`.
+    ///
+    /// Unlike `read_into_slice`, this allows choosing the resampling algorithm used when
+    /// window_size != size, and reading from a fractional source window.
+    ///
+    /// # Arguments
+    /// * window - the window position from top left
+    /// * window_size - the window size
+    /// * size - the desired size to read
+    /// * buffer - a slice to hold the data (length must equal product of size parameter)
+    /// * extra_arg - the resampling algorithm and optional floating point source window
+    pub fn read_into_slice_ex<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+        buffer: &mut [T],
+        extra_arg: &RasterIOExtraArg,
+    ) -> Result<()> {
+        check_window(window, window_size, self.size())?;
+        check_buffer_len(buffer.len(), size, 1)?;
+        self._read_ex::<T>(
+            window,
+            window_size,
+            size,
+            buffer.as_mut_ptr() as *mut c_void,
+            extra_arg,
+        )
+    }
+
+    /// Read `size` pixels of type `T` into `c_buffer` with `GDALRasterIOEx`.
+    fn _read_ex<T: GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+        c_buffer: *mut c_void,
+        extra_arg: &RasterIOExtraArg,
+    ) -> Result<()> {
+        let mut c_extra_arg = extra_arg.to_c_extra_arg();
+        let rv = unsafe {
+            gdal_sys::GDALRasterIOEx(
+                self.c_rasterband,
+                GDALRWFlag::GF_Read,
+                window.0 as c_int,
+                window.1 as c_int,
+                window_size.0 as c_int,
+                window_size.1 as c_int,
+                c_buffer,
+                size.0 as c_int,
+                size.1 as c_int,
+                T::gdal_type(),
+                0,
+                0,
+                &mut c_extra_arg,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+
+        Ok(())
+    }
+
     /// Read a 'Buffer<T>' from this band. T implements 'GdalType'
     ///
     /// # Arguments
@@ -137,6 +227,87 @@ impl<'a> RasterBand<'a> {
         Ok(Buffer { size, data })
     }
 
+    /// Read a 'Buffer<T>' from this band, using `GDALRasterIOEx`. T implements 'GdalType'
+    ///
+    /// # Arguments
+    /// * window - the window position from top left
+    /// * window_size - the window size
+    /// * size - the desired size of the 'Buffer'
+    /// * extra_arg - the resampling algorithm and optional floating point source window
+    pub fn read_as_ex<T: Copy + GdalType>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+        extra_arg: &RasterIOExtraArg,
+    ) -> Result<Buffer<T>> {
+        let pixels = size.0 * size.1;
+
+        check_window(window, window_size, self.size())?;
+
+        let mut data: Vec<T> = Vec::with_capacity(pixels);
+        self._read_ex::<T>(
+            window,
+            window_size,
+            size,
+            data.as_mut_ptr() as *mut c_void,
+            extra_arg,
+        )?;
+        // Safety: GDALRasterIOEx has written all pixels into the allocated capacity
+        unsafe {
+            data.set_len(pixels);
+        };
+
+        Ok(Buffer { size, data })
+    }
+
+    /// Read a 'DynBuffer' from this band, using the band's own data type.
+    ///
+    /// # Arguments
+    /// * window - the window position from top left
+    /// * window_size - the window size (GDAL will interpolate data if window_size != buffer_size)
+    /// * size - the desired size of the 'DynBuffer'
+    pub fn read_native(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+    ) -> Result<DynBuffer> {
+        let buffer = match self.band_type() {
+            GDALDataType::GDT_Byte => DynBuffer::U8(self.read_as(window, window_size, size)?),
+            GDALDataType::GDT_UInt16 => DynBuffer::U16(self.read_as(window, window_size, size)?),
+            GDALDataType::GDT_Int16 => DynBuffer::I16(self.read_as(window, window_size, size)?),
+            GDALDataType::GDT_UInt32 => DynBuffer::U32(self.read_as(window, window_size, size)?),
+            GDALDataType::GDT_Int32 => DynBuffer::I32(self.read_as(window, window_size, size)?),
+            GDALDataType::GDT_Float32 => DynBuffer::F32(self.read_as(window, window_size, size)?),
+            GDALDataType::GDT_Float64 => DynBuffer::F64(self.read_as(window, window_size, size)?),
+            #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+            GDALDataType::GDT_Int64 => DynBuffer::I64(self.read_as(window, window_size, size)?),
+            #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+            GDALDataType::GDT_UInt64 => DynBuffer::U64(self.read_as(window, window_size, size)?),
+            #[cfg(any(all(major_is_3, minor_ge_7), major_ge_4))]
+            GDALDataType::GDT_Int8 => DynBuffer::I8(self.read_as(window, window_size, size)?),
+            #[cfg(feature = "complex")]
+            GDALDataType::GDT_CInt16 => {
+                DynBuffer::CInt16(self.read_as(window, window_size, size)?)
+            }
+            #[cfg(feature = "complex")]
+            GDALDataType::GDT_CInt32 => {
+                DynBuffer::CInt32(self.read_as(window, window_size, size)?)
+            }
+            #[cfg(feature = "complex")]
+            GDALDataType::GDT_CFloat32 => {
+                DynBuffer::CFloat32(self.read_as(window, window_size, size)?)
+            }
+            #[cfg(feature = "complex")]
+            GDALDataType::GDT_CFloat64 => {
+                DynBuffer::CFloat64(self.read_as(window, window_size, size)?)
+            }
+            data_type => return Err(ErrorKind::UnsupportedDataType { data_type }.into()),
+        };
+        Ok(buffer)
+    }
+
     #[cfg(feature = "ndarray")]
     /// Read a 'Array2<T>' from this band. T implements 'GdalType'.
     ///
@@ -201,6 +372,85 @@ impl<'a> RasterBand<'a> {
         Array2::from_shape_vec((size.1, size.0), data).map_err(Into::into)
     }
 
+    /// Get the number of blocks along the x and y axis, including partial edge blocks.
+    pub fn block_count(&self) -> (usize, usize) {
+        let (block_x, block_y) = self.block_size();
+        let (size_x, size_y) = self.size();
+        (
+            size_x / block_x + (size_x % block_x != 0) as usize,
+            size_y / block_y + (size_y % block_y != 0) as usize,
+        )
+    }
+
+    /// Get the window `(offset, size)` covered by a block, trimmed to the band size
+    /// for partial blocks at the right and bottom edges.
+    pub fn block_window(
+        &self,
+        block_index: (usize, usize),
+    ) -> Result<((isize, isize), (usize, usize))> {
+        let block_count = self.block_count();
+        if block_index.0 >= block_count.0 || block_index.1 >= block_count.1 {
+            return Err(ErrorKind::InvalidBlockIndex {
+                block_index,
+                block_count,
+            }
+            .into());
+        }
+        let (block_x, block_y) = self.block_size();
+        let (size_x, size_y) = self.size();
+        let offset = (block_index.0 * block_x, block_index.1 * block_y);
+        let size = (
+            block_x.min(size_x - offset.0),
+            block_y.min(size_y - offset.1),
+        );
+        Ok(((offset.0 as isize, offset.1 as isize), size))
+    }
+
+    /// Iterate over all blocks of this band in row-major order, reading each one
+    /// as a 'Buffer<T>'. T implements 'GdalType'.
+    ///
+    /// Each item is `(block_index, window, buffer)`, where `window` is the offset of the
+    /// block and `buffer.size` its size, trimmed at the edges of the band.
+    ///
+    /// ```
+    /// # use std::path::Path;
+    /// # use gdal::Dataset;
+    /// let dataset = Dataset::open(Path::new("fixtures/tinymarble.png")).unwrap();
+    /// let band = dataset.rasterband(1).unwrap();
+    /// for block in band.blocks::<u8>() {
+    ///     let (block_index, window, buffer) = block.unwrap();
+    ///     // process the block
+    /// }
+    /// ```
+    pub fn blocks<T: Copy + GdalType>(&self) -> BlockIterator<T> {
+        BlockIterator {
+            band: self,
+            block_count: self.block_count(),
+            next_index: (0, 0),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Write a 'Buffer<T>' into the block at `block_index`.
+    ///
+    /// The buffer size must match the size of the block as returned by `block_window`,
+    /// which is smaller than `block_size` for partial blocks at the edges.
+    pub fn write_block<T: GdalType + Copy>(
+        &self,
+        block_index: (usize, usize),
+        buffer: &Buffer<T>,
+    ) -> Result<()> {
+        let (offset, size) = self.block_window(block_index)?;
+        if buffer.size != size {
+            return Err(ErrorKind::BufferSizeMismatch {
+                size: buffer.size,
+                expected: size,
+            }
+            .into());
+        }
+        self.write(offset, size, buffer)
+    }
+
     // Write a 'Buffer<T>' into a 'Dataset'.
     /// # Arguments
     /// * band_index - the band_index
@@ -212,7 +462,8 @@ impl<'a> RasterBand<'a> {
         window_size: (usize, usize),
         buffer: &Buffer<T>,
     ) -> Result<()> {
-        assert_eq!(buffer.data.len(), buffer.size.0 * buffer.size.1);
+        check_window(window, window_size, self.size())?;
+        check_buffer_len(buffer.data.len(), buffer.size, 1)?;
         let rv = unsafe {
             gdal_sys::GDALRasterIO(
                 self.c_rasterband,
@@ -235,6 +486,107 @@ impl<'a> RasterBand<'a> {
         Ok(())
     }
 
+    #[cfg(feature = "ndarray")]
+    /// Write an 'ArrayView2<T>' into this band. T implements 'GdalType'.
+    ///
+    /// The view does not need to be contiguous: its strides are passed to GDAL as
+    /// pixel and line spacing, so e.g. transposed views or slices with a step work as well.
+    ///
+    /// # Arguments
+    /// * window - the window position from top left
+    /// * window_size - the window size (GDAL will interpolate data if window_size != array_size)
+    /// * array - the data to write
+    /// # Docs
+    /// The Matrix shape is (rows, cols) and raster shape is (cols in x-axis, rows in y-axis).
+    pub fn write_array<T: GdalType + Copy>(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        array: ArrayView2<T>,
+    ) -> Result<()> {
+        check_window(window, window_size, self.size())?;
+        let (rows, cols) = array.dim();
+        let type_size = std::mem::size_of::<T>() as gdal_sys::GSpacing;
+        let strides = array.strides();
+        let rv = unsafe {
+            gdal_sys::GDALRasterIOEx(
+                self.c_rasterband,
+                GDALRWFlag::GF_Write,
+                window.0 as c_int,
+                window.1 as c_int,
+                window_size.0 as c_int,
+                window_size.1 as c_int,
+                array.as_ptr() as GDALRasterBandH,
+                cols as c_int,
+                rows as c_int,
+                T::gdal_type(),
+                strides[1] as gdal_sys::GSpacing * type_size,
+                strides[0] as gdal_sys::GSpacing * type_size,
+                ptr::null_mut(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Get the number of overviews (reduced resolution versions) of this band.
+    pub fn overview_count(&self) -> isize {
+        (unsafe { gdal_sys::GDALGetOverviewCount(self.c_rasterband) }) as isize
+    }
+
+    /// Get an overview of this band, from 0 to `overview_count() - 1`.
+    ///
+    /// Overviews are usually ordered from the highest to the lowest resolution.
+    pub fn overview(&self, overview_index: isize) -> Result<RasterBand<'a>> {
+        let c_band =
+            unsafe { gdal_sys::GDALGetOverview(self.c_rasterband, overview_index as c_int) };
+        if c_band.is_null() {
+            return Err(_last_null_pointer_err("GDALGetOverview").into());
+        }
+        Ok(RasterBand {
+            c_rasterband: c_band,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Get the flags describing the mask band of this band.
+    pub fn mask_flags(&self) -> GdalMaskFlags {
+        let flags = unsafe { gdal_sys::GDALGetMaskFlags(self.c_rasterband) };
+        GdalMaskFlags::from_bits_truncate(flags)
+    }
+
+    /// Get the mask band of this band, where 0 marks invalid and non-zero marks valid pixels.
+    ///
+    /// A mask band is always available: GDAL falls back to one derived from the alpha band,
+    /// the nodata value or an all-valid mask, see `mask_flags`.
+    pub fn mask_band(&self) -> Result<RasterBand<'a>> {
+        let c_band = unsafe { gdal_sys::GDALGetMaskBand(self.c_rasterband) };
+        if c_band.is_null() {
+            return Err(_last_null_pointer_err("GDALGetMaskBand").into());
+        }
+        Ok(RasterBand {
+            c_rasterband: c_band,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Create a mask band for this band, or for all bands of the dataset if `per_dataset`
+    /// is set. Depending on the driver, the mask is stored internally or in a `.msk` file.
+    pub fn create_mask_band(&self, per_dataset: bool) -> Result<()> {
+        let flags = if per_dataset {
+            GdalMaskFlags::GMF_PER_DATASET
+        } else {
+            GdalMaskFlags::empty()
+        };
+        let rv = unsafe { gdal_sys::GDALCreateMaskBand(self.c_rasterband, flags.bits()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
     pub fn band_type(&self) -> GDALDataType::Type {
         unsafe { gdal_sys::GDALGetRasterDataType(self.c_rasterband) }
     }
@@ -275,6 +627,229 @@ impl<'a> RasterBand<'a> {
         None
     }
 
+    /// Get the color interpretation of this band.
+    pub fn color_interpretation(&self) -> ColorInterpretation {
+        ColorInterpretation::from_gdal(unsafe {
+            gdal_sys::GDALGetRasterColorInterpretation(self.c_rasterband)
+        })
+    }
+
+    pub fn set_color_interpretation(&self, color_interp: ColorInterpretation) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::GDALSetRasterColorInterpretation(self.c_rasterband, color_interp.to_gdal())
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Get a copy of the color table of this band, if it has one.
+    pub fn color_table(&self) -> Option<ColorTable> {
+        let c_color_table = unsafe { gdal_sys::GDALGetRasterColorTable(self.c_rasterband) };
+        if c_color_table.is_null() {
+            return None;
+        }
+        unsafe { ColorTable::from_c_color_table(c_color_table) }.ok()
+    }
+
+    /// Set the color table of this band. GDAL copies the table.
+    pub fn set_color_table(&self, color_table: &ColorTable) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::GDALSetRasterColorTable(self.c_rasterband, color_table.c_color_table())
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Get a copy of the default raster attribute table of this band, if it has one.
+    pub fn default_rat(&self) -> Option<RasterAttributeTable> {
+        let c_rat = unsafe { gdal_sys::GDALGetDefaultRAT(self.c_rasterband) };
+        if c_rat.is_null() {
+            return None;
+        }
+        unsafe { RasterAttributeTable::from_c_rat(c_rat) }.ok()
+    }
+
+    /// Set the default raster attribute table of this band. GDAL copies the table.
+    pub fn set_default_rat(&self, rat: &RasterAttributeTable) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALSetDefaultRAT(self.c_rasterband, rat.c_rat()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Compute the exact or approximate statistics of this band.
+    ///
+    /// The result is also stored as band metadata (and in a `.aux.xml` file for formats
+    /// that support it).
+    pub fn compute_statistics(&self, approx_ok: bool) -> Result<Statistics> {
+        let mut stats = Statistics::default();
+        let rv = unsafe {
+            gdal_sys::GDALComputeRasterStatistics(
+                self.c_rasterband,
+                approx_ok as c_int,
+                &mut stats.min,
+                &mut stats.max,
+                &mut stats.mean,
+                &mut stats.std_dev,
+                None,
+                ptr::null_mut(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(stats)
+    }
+
+    /// Fetch the statistics of this band.
+    ///
+    /// Returns `None` if no statistics are available and `force` is `false`.
+    /// With `force`, the statistics are computed if needed.
+    pub fn get_statistics(&self, approx_ok: bool, force: bool) -> Result<Option<Statistics>> {
+        let mut stats = Statistics::default();
+        let rv = unsafe {
+            gdal_sys::GDALGetRasterStatistics(
+                self.c_rasterband,
+                approx_ok as c_int,
+                force as c_int,
+                &mut stats.min,
+                &mut stats.max,
+                &mut stats.mean,
+                &mut stats.std_dev,
+            )
+        };
+        match rv {
+            CPLErr::CE_None => Ok(Some(stats)),
+            CPLErr::CE_Warning => Ok(None),
+            _ => Err(_last_cpl_err(rv).into()),
+        }
+    }
+
+    /// Set the statistics of this band, e.g. to store precomputed values.
+    pub fn set_statistics(&self, stats: &Statistics) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::GDALSetRasterStatistics(
+                self.c_rasterband,
+                stats.min,
+                stats.max,
+                stats.mean,
+                stats.std_dev,
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Compute the minimum and maximum values of this band as `(min, max)`.
+    pub fn compute_min_max(&self, approx_ok: bool) -> Result<(f64, f64)> {
+        let mut min_max = [0.0; 2];
+        unsafe {
+            gdal_sys::CPLErrorReset();
+            gdal_sys::GDALComputeRasterMinMax(
+                self.c_rasterband,
+                approx_ok as c_int,
+                min_max.as_mut_ptr(),
+            );
+        }
+        let last_err = unsafe { gdal_sys::CPLGetLastErrorType() };
+        if last_err == CPLErr::CE_Failure || last_err == CPLErr::CE_Fatal {
+            return Err(_last_cpl_err(last_err).into());
+        }
+        Ok((min_max[0], min_max[1]))
+    }
+
+    /// Compute a histogram of this band with `buckets` equally sized buckets between
+    /// `min` and `max`.
+    ///
+    /// # Arguments
+    /// * buckets - the number of buckets
+    /// * min - the lower bound of the first bucket
+    /// * max - the upper bound of the last bucket
+    /// * include_out_of_range - count values outside `[min, max]` in the first or last bucket
+    /// * approx_ok - allow computing the histogram from overviews or a subsample
+    pub fn histogram(
+        &self,
+        buckets: usize,
+        min: f64,
+        max: f64,
+        include_out_of_range: bool,
+        approx_ok: bool,
+    ) -> Result<Histogram> {
+        let mut counts = vec![0u64; buckets];
+        let rv = unsafe {
+            gdal_sys::GDALGetRasterHistogramEx(
+                self.c_rasterband,
+                min,
+                max,
+                buckets as c_int,
+                counts.as_mut_ptr() as *mut GUIntBig,
+                include_out_of_range as c_int,
+                approx_ok as c_int,
+                None,
+                ptr::null_mut(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(Histogram { min, max, counts })
+    }
+
+    pub fn set_scale(&self, scale: f64) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALSetRasterScale(self.c_rasterband, scale) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    pub fn set_offset(&self, offset: f64) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALSetRasterOffset(self.c_rasterband, offset) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Get the unit of the pixel values, e.g. `"m"` or `"K"`.
+    /// Returns an empty string if no unit is set.
+    pub fn unit_type(&self) -> String {
+        _string(unsafe { gdal_sys::GDALGetRasterUnitType(self.c_rasterband) })
+    }
+
+    pub fn set_unit_type(&self, unit_type: &str) -> Result<()> {
+        let c_unit_type = CString::new(unit_type)?;
+        let rv =
+            unsafe { gdal_sys::GDALSetRasterUnitType(self.c_rasterband, c_unit_type.as_ptr()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Get the names of the categories of a thematic band, indexed by pixel value.
+    pub fn category_names(&self) -> Vec<String> {
+        _string_array(unsafe { gdal_sys::GDALGetRasterCategoryNames(self.c_rasterband) })
+    }
+
+    pub fn set_category_names<S: AsRef<str>>(&self, names: &[S]) -> Result<()> {
+        let c_names = CStringList::new(names)?;
+        let rv = unsafe {
+            gdal_sys::GDALSetRasterCategoryNames(self.c_rasterband, c_names.as_ptr() as _)
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
     /// Get actual block size (at the edges) when block size
     /// does not divide band size.
     #[cfg(any(all(major_is_2, minor_ge_2), major_ge_3))] // GDAL 2.2 .. 2.x or >= 3
@@ -305,6 +880,174 @@ impl<'a> MajorObject for RasterBand<'a> {
 
 impl<'a> Metadata for RasterBand<'a> {}
 
+/// Summary statistics of a raster band.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Statistics {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+/// A histogram of a raster band, as returned by `RasterBand::histogram`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    /// Lower bound of the first bucket.
+    pub min: f64,
+    /// Upper bound of the last bucket.
+    pub max: f64,
+    /// Number of values in each bucket.
+    pub counts: Vec<u64>,
+}
+
+impl Histogram {
+    /// Width of a single bucket.
+    pub fn bucket_size(&self) -> f64 {
+        (self.max - self.min) / self.counts.len() as f64
+    }
+
+    /// Value range `(lower, upper)` covered by the bucket at `index`.
+    pub fn bucket_range(&self, index: usize) -> (f64, f64) {
+        let lower = self.min + index as f64 * self.bucket_size();
+        (lower, lower + self.bucket_size())
+    }
+}
+
+/// Resampling algorithm used by `RasterBand::read_as_ex` and `RasterBand::read_into_slice_ex`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResampleAlg {
+    NearestNeighbour,
+    Bilinear,
+    Cubic,
+    CubicSpline,
+    Lanczos,
+    Average,
+    Mode,
+    Gauss,
+}
+
+impl ResampleAlg {
+    pub fn to_gdal(self) -> GDALRIOResampleAlg::Type {
+        match self {
+            ResampleAlg::NearestNeighbour => GDALRIOResampleAlg::GRIORA_NearestNeighbour,
+            ResampleAlg::Bilinear => GDALRIOResampleAlg::GRIORA_Bilinear,
+            ResampleAlg::Cubic => GDALRIOResampleAlg::GRIORA_Cubic,
+            ResampleAlg::CubicSpline => GDALRIOResampleAlg::GRIORA_CubicSpline,
+            ResampleAlg::Lanczos => GDALRIOResampleAlg::GRIORA_Lanczos,
+            ResampleAlg::Average => GDALRIOResampleAlg::GRIORA_Average,
+            ResampleAlg::Mode => GDALRIOResampleAlg::GRIORA_Mode,
+            ResampleAlg::Gauss => GDALRIOResampleAlg::GRIORA_Gauss,
+        }
+    }
+}
+
+/// Extra arguments for `RasterBand::read_as_ex`, mirroring `GDALRasterIOExtraArg`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RasterIOExtraArg {
+    pub resample_alg: ResampleAlg,
+    /// Source window with sub-pixel precision as (x_off, y_off, x_size, y_size).
+    /// When set, it takes precedence over the integer window passed alongside.
+    pub floating_point_window: Option<(f64, f64, f64, f64)>,
+}
+
+impl Default for RasterIOExtraArg {
+    fn default() -> Self {
+        RasterIOExtraArg::new(ResampleAlg::NearestNeighbour)
+    }
+}
+
+impl RasterIOExtraArg {
+    pub fn new(resample_alg: ResampleAlg) -> Self {
+        RasterIOExtraArg {
+            resample_alg,
+            floating_point_window: None,
+        }
+    }
+
+    pub fn floating_point_window(mut self, window: (f64, f64, f64, f64)) -> Self {
+        self.floating_point_window = Some(window);
+        self
+    }
+
+    fn to_c_extra_arg(self) -> GDALRasterIOExtraArg {
+        let (x_off, y_off, x_size, y_size) = self.floating_point_window.unwrap_or_default();
+        GDALRasterIOExtraArg {
+            // RASTERIO_EXTRA_ARG_CURRENT_VERSION
+            nVersion: 1,
+            eResampleAlg: self.resample_alg.to_gdal(),
+            pfnProgress: None,
+            pProgressData: ptr::null_mut(),
+            bFloatingPointWindowValidity: self.floating_point_window.is_some() as c_int,
+            dfXOff: x_off,
+            dfYOff: y_off,
+            dfXSize: x_size,
+            dfYSize: y_size,
+        }
+    }
+}
+
+/// Check that a window lies within a raster of `raster_size`.
+pub(crate) fn check_window(
+    window: (isize, isize),
+    window_size: (usize, usize),
+    raster_size: (usize, usize),
+) -> Result<()> {
+    let fits = |offset: isize, size: usize, raster_size: usize| {
+        offset >= 0 && offset as usize + size <= raster_size
+    };
+    if !fits(window.0, window_size.0, raster_size.0)
+        || !fits(window.1, window_size.1, raster_size.1)
+    {
+        return Err(ErrorKind::InvalidWindow {
+            window,
+            window_size,
+            raster_size,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Check that a buffer of `len` elements holds exactly `size` pixels for `band_count` bands.
+pub(crate) fn check_buffer_len(len: usize, size: (usize, usize), band_count: usize) -> Result<()> {
+    let expected = size.0 * size.1 * band_count;
+    if len != expected {
+        return Err(ErrorKind::BufferLengthMismatch { len, expected }.into());
+    }
+    Ok(())
+}
+
+/// Iterator over the blocks of a band, created by `RasterBand::blocks`.
+pub struct BlockIterator<'a, T: Copy + GdalType> {
+    band: &'a RasterBand<'a>,
+    block_count: (usize, usize),
+    next_index: (usize, usize),
+    phantom: PhantomData<T>,
+}
+
+impl<'a, T: Copy + GdalType> Iterator for BlockIterator<'a, T> {
+    type Item = Result<((usize, usize), (isize, isize), Buffer<T>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index.1 >= self.block_count.1 || self.block_count.0 == 0 {
+            return None;
+        }
+        let block_index = self.next_index;
+        self.next_index.0 += 1;
+        if self.next_index.0 == self.block_count.0 {
+            self.next_index = (0, self.next_index.1 + 1);
+        }
+
+        let (offset, size) = match self.band.block_window(block_index) {
+            Ok(window) => window,
+            Err(e) => return Some(Err(e)),
+        };
+        let block = self.band.read_as::<T>(offset, size, size);
+        Some(block.map(|buffer| (block_index, offset, buffer)))
+    }
+}
+
+#[derive(Debug)]
 pub struct Buffer<T: GdalType> {
     pub size: (usize, usize),
     pub data: Vec<T>,
@@ -317,3 +1060,134 @@ impl<T: GdalType> Buffer<T> {
 }
 
 pub type ByteBuffer = Buffer<u8>;
+
+/// A 'Buffer' whose pixel type is only known at runtime, as returned by
+/// `RasterBand::read_native`.
+#[derive(Debug)]
+pub enum DynBuffer {
+    U8(Buffer<u8>),
+    U16(Buffer<u16>),
+    I16(Buffer<i16>),
+    U32(Buffer<u32>),
+    I32(Buffer<i32>),
+    F32(Buffer<f32>),
+    F64(Buffer<f64>),
+    #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+    I64(Buffer<i64>),
+    #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+    U64(Buffer<u64>),
+    #[cfg(any(all(major_is_3, minor_ge_7), major_ge_4))]
+    I8(Buffer<i8>),
+    #[cfg(feature = "complex")]
+    CInt16(Buffer<Complex<i16>>),
+    #[cfg(feature = "complex")]
+    CInt32(Buffer<Complex<i32>>),
+    #[cfg(feature = "complex")]
+    CFloat32(Buffer<Complex<f32>>),
+    #[cfg(feature = "complex")]
+    CFloat64(Buffer<Complex<f64>>),
+}
+
+impl DynBuffer {
+    /// The size of the buffer as (cols, rows).
+    pub fn size(&self) -> (usize, usize) {
+        match self {
+            DynBuffer::U8(buffer) => buffer.size,
+            DynBuffer::U16(buffer) => buffer.size,
+            DynBuffer::I16(buffer) => buffer.size,
+            DynBuffer::U32(buffer) => buffer.size,
+            DynBuffer::I32(buffer) => buffer.size,
+            DynBuffer::F32(buffer) => buffer.size,
+            DynBuffer::F64(buffer) => buffer.size,
+            #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+            DynBuffer::I64(buffer) => buffer.size,
+            #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+            DynBuffer::U64(buffer) => buffer.size,
+            #[cfg(any(all(major_is_3, minor_ge_7), major_ge_4))]
+            DynBuffer::I8(buffer) => buffer.size,
+            #[cfg(feature = "complex")]
+            DynBuffer::CInt16(buffer) => buffer.size,
+            #[cfg(feature = "complex")]
+            DynBuffer::CInt32(buffer) => buffer.size,
+            #[cfg(feature = "complex")]
+            DynBuffer::CFloat32(buffer) => buffer.size,
+            #[cfg(feature = "complex")]
+            DynBuffer::CFloat64(buffer) => buffer.size,
+        }
+    }
+
+    /// The GDAL data type of the pixels.
+    pub fn data_type(&self) -> GDALDataType::Type {
+        match self {
+            DynBuffer::U8(_) => u8::gdal_type(),
+            DynBuffer::U16(_) => u16::gdal_type(),
+            DynBuffer::I16(_) => i16::gdal_type(),
+            DynBuffer::U32(_) => u32::gdal_type(),
+            DynBuffer::I32(_) => i32::gdal_type(),
+            DynBuffer::F32(_) => f32::gdal_type(),
+            DynBuffer::F64(_) => f64::gdal_type(),
+            #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+            DynBuffer::I64(_) => i64::gdal_type(),
+            #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+            DynBuffer::U64(_) => u64::gdal_type(),
+            #[cfg(any(all(major_is_3, minor_ge_7), major_ge_4))]
+            DynBuffer::I8(_) => i8::gdal_type(),
+            #[cfg(feature = "complex")]
+            DynBuffer::CInt16(_) => Complex::<i16>::gdal_type(),
+            #[cfg(feature = "complex")]
+            DynBuffer::CInt32(_) => Complex::<i32>::gdal_type(),
+            #[cfg(feature = "complex")]
+            DynBuffer::CFloat32(_) => Complex::<f32>::gdal_type(),
+            #[cfg(feature = "complex")]
+            DynBuffer::CFloat64(_) => Complex::<f64>::gdal_type(),
+        }
+    }
+}
+
+/// The order of the band values in a `MultiBandBuffer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interleave {
+    /// Band sequential: all values of the first band, then all values of the second band, etc.
+    Band,
+    /// Pixel interleaved: all band values of the first pixel, then all band values of the second pixel, etc.
+    Pixel,
+}
+
+impl Interleave {
+    /// The pixel, line and band spacing in bytes of a buffer of `T` with this interleave.
+    pub fn spacing<T>(self, size: (usize, usize), band_count: usize) -> (usize, usize, usize) {
+        let type_size = std::mem::size_of::<T>();
+        match self {
+            Interleave::Band => (type_size, type_size * size.0, type_size * size.0 * size.1),
+            Interleave::Pixel => (
+                type_size * band_count,
+                type_size * band_count * size.0,
+                type_size,
+            ),
+        }
+    }
+}
+
+/// Pixel values of several bands, as read by `Dataset::read_as`.
+pub struct MultiBandBuffer<T: GdalType> {
+    pub size: (usize, usize),
+    pub band_count: usize,
+    pub interleave: Interleave,
+    pub data: Vec<T>,
+}
+
+impl<T: GdalType> MultiBandBuffer<T> {
+    pub fn new(
+        size: (usize, usize),
+        band_count: usize,
+        interleave: Interleave,
+        data: Vec<T>,
+    ) -> MultiBandBuffer<T> {
+        MultiBandBuffer {
+            size,
+            band_count,
+            interleave,
+            data,
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::dataset::Dataset;
+use crate::utils::{_last_cpl_err, CStringList};
+use crate::vector::{Geometry, Layer};
+use gdal_sys::{self, CPLErr, OGRGeometryH, OGRLayerH};
+use libc::c_int;
+use std::ptr::null_mut;
+
+use crate::errors::*;
+
+/// How burned values are combined with the existing pixel values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeAlgorithm {
+    /// Overwrite the existing value.
+    Replace,
+    /// Add the burned value to the existing value.
+    Add,
+}
+
+/// What a layer is burned with in `rasterize_layers`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BurnSource {
+    /// One value per layer, or one value per layer and band.
+    Values(Vec<f64>),
+    /// The value of this numeric attribute field, per feature.
+    Attribute(String),
+}
+
+/// Options for `rasterize` and `rasterize_layers`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RasterizeOptions {
+    all_touched: bool,
+    merge_algorithm: MergeAlgorithm,
+    burn_z: bool,
+}
+
+impl Default for RasterizeOptions {
+    fn default() -> Self {
+        RasterizeOptions::new()
+    }
+}
+
+impl RasterizeOptions {
+    /// Replace the pixels whose center is inside a geometry, or which are on the
+    /// rendered path of a line.
+    pub fn new() -> Self {
+        RasterizeOptions {
+            all_touched: false,
+            merge_algorithm: MergeAlgorithm::Replace,
+            burn_z: false,
+        }
+    }
+
+    /// Burn all pixels touched by a geometry, not only those whose center is inside.
+    pub fn all_touched(mut self, all_touched: bool) -> Self {
+        self.all_touched = all_touched;
+        self
+    }
+
+    pub fn merge_algorithm(mut self, merge_algorithm: MergeAlgorithm) -> Self {
+        self.merge_algorithm = merge_algorithm;
+        self
+    }
+
+    /// Burn the Z values of the geometries, plus the burn value.
+    pub fn burn_z(mut self, burn_z: bool) -> Self {
+        self.burn_z = burn_z;
+        self
+    }
+
+    fn to_options_list(self, attribute: Option<&str>) -> Result<CStringList> {
+        let mut options = Vec::new();
+        if self.all_touched {
+            options.push("ALL_TOUCHED=TRUE".to_string());
+        }
+        if self.merge_algorithm == MergeAlgorithm::Add {
+            options.push("MERGE_ALG=ADD".to_string());
+        }
+        if self.burn_z {
+            options.push("BURN_VALUE_FROM=Z".to_string());
+        }
+        if let Some(attribute) = attribute {
+            options.push(format!("ATTRIBUTE={}", attribute));
+        }
+        CStringList::new(&options)
+    }
+}
+
+/// Expand one burn value per geometry or layer to one value per geometry or layer and band.
+fn _burn_values(values: &[f64], item_count: usize, band_count: usize) -> Result<Vec<f64>> {
+    if values.len() == item_count * band_count {
+        return Ok(values.to_vec());
+    }
+    if values.len() != item_count {
+        return Err(ErrorKind::InvalidBurnValueCount {
+            count: values.len(),
+            item_count,
+            per_band_count: item_count * band_count,
+        }
+        .into());
+    }
+    Ok(values
+        .iter()
+        .flat_map(|&value| vec![value; band_count])
+        .collect())
+}
+
+/// Burn `geometries` into `bands` of `dataset`.
+///
+/// The geometries are in the georeferenced coordinates of `dataset`. `burn_values`
+/// holds one value per geometry, or one value per geometry and band, ordered by
+/// geometry.
+pub fn rasterize(
+    dataset: &Dataset,
+    bands: &[isize],
+    geometries: &[Geometry],
+    burn_values: &[f64],
+    options: &RasterizeOptions,
+) -> Result<()> {
+    let mut burn_values = _burn_values(burn_values, geometries.len(), bands.len())?;
+    let mut bands: Vec<c_int> = bands.iter().map(|&band| band as c_int).collect();
+    let mut c_geometries: Vec<OGRGeometryH> = geometries
+        .iter()
+        .map(|geometry| unsafe { geometry.c_geometry() })
+        .collect();
+    let c_options = options.to_options_list(None)?;
+    let rv = unsafe {
+        gdal_sys::GDALRasterizeGeometries(
+            dataset.c_dataset(),
+            bands.len() as c_int,
+            bands.as_mut_ptr(),
+            c_geometries.len() as c_int,
+            c_geometries.as_mut_ptr(),
+            None,
+            null_mut(),
+            burn_values.as_mut_ptr(),
+            c_options.as_ptr(),
+            None,
+            null_mut(),
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(_last_cpl_err(rv).into());
+    }
+    Ok(())
+}
+
+/// Burn the features of `layers` into `bands` of `dataset`.
+///
+/// The features are reprojected to the spatial reference of `dataset` if both are
+/// known.
+pub fn rasterize_layers(
+    dataset: &Dataset,
+    bands: &[isize],
+    layers: &[&Layer],
+    burn: &BurnSource,
+    options: &RasterizeOptions,
+) -> Result<()> {
+    let (mut burn_values, attribute) = match burn {
+        BurnSource::Values(values) => (_burn_values(values, layers.len(), bands.len())?, None),
+        BurnSource::Attribute(attribute) => (Vec::new(), Some(attribute.as_str())),
+    };
+    let mut bands: Vec<c_int> = bands.iter().map(|&band| band as c_int).collect();
+    let mut c_layers: Vec<OGRLayerH> = layers
+        .iter()
+        .map(|layer| unsafe { layer.c_layer() })
+        .collect();
+    let c_options = options.to_options_list(attribute)?;
+    let rv = unsafe {
+        gdal_sys::GDALRasterizeLayers(
+            dataset.c_dataset(),
+            bands.len() as c_int,
+            bands.as_mut_ptr(),
+            c_layers.len() as c_int,
+            c_layers.as_mut_ptr(),
+            None,
+            null_mut(),
+            if burn_values.is_empty() {
+                null_mut()
+            } else {
+                burn_values.as_mut_ptr()
+            },
+            c_options.as_ptr(),
+            None,
+            null_mut(),
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(_last_cpl_err(rv).into());
+    }
+    Ok(())
+}
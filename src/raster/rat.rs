@@ -0,0 +1,366 @@
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
+use crate::vector::{Feature, FieldValue, Layer, OGRFieldType};
+use gdal_sys::{self, CPLErr, GDALRATFieldType, GDALRATFieldUsage, GDALRasterAttributeTableH};
+use libc::c_int;
+use std::ffi::CString;
+
+use crate::errors::*;
+
+/// The type of a raster attribute table column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RatFieldType {
+    Integer,
+    Real,
+    String,
+}
+
+impl RatFieldType {
+    fn from_gdal(field_type: GDALRATFieldType::Type) -> Self {
+        match field_type {
+            GDALRATFieldType::GFT_Integer => RatFieldType::Integer,
+            GDALRATFieldType::GFT_Real => RatFieldType::Real,
+            _ => RatFieldType::String,
+        }
+    }
+
+    fn to_gdal(self) -> GDALRATFieldType::Type {
+        match self {
+            RatFieldType::Integer => GDALRATFieldType::GFT_Integer,
+            RatFieldType::Real => GDALRATFieldType::GFT_Real,
+            RatFieldType::String => GDALRATFieldType::GFT_String,
+        }
+    }
+
+    fn to_ogr(self) -> OGRFieldType::Type {
+        match self {
+            RatFieldType::Integer => OGRFieldType::OFTInteger,
+            RatFieldType::Real => OGRFieldType::OFTReal,
+            RatFieldType::String => OGRFieldType::OFTString,
+        }
+    }
+}
+
+/// The meaning of a raster attribute table column, e.g. the class name or a color component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RatFieldUsage {
+    Generic,
+    PixelCount,
+    Name,
+    Min,
+    Max,
+    MinMax,
+    Red,
+    Green,
+    Blue,
+    Alpha,
+    RedMin,
+    GreenMin,
+    BlueMin,
+    AlphaMin,
+    RedMax,
+    GreenMax,
+    BlueMax,
+    AlphaMax,
+}
+
+impl RatFieldUsage {
+    fn from_gdal(usage: GDALRATFieldUsage::Type) -> Self {
+        match usage {
+            GDALRATFieldUsage::GFU_PixelCount => RatFieldUsage::PixelCount,
+            GDALRATFieldUsage::GFU_Name => RatFieldUsage::Name,
+            GDALRATFieldUsage::GFU_Min => RatFieldUsage::Min,
+            GDALRATFieldUsage::GFU_Max => RatFieldUsage::Max,
+            GDALRATFieldUsage::GFU_MinMax => RatFieldUsage::MinMax,
+            GDALRATFieldUsage::GFU_Red => RatFieldUsage::Red,
+            GDALRATFieldUsage::GFU_Green => RatFieldUsage::Green,
+            GDALRATFieldUsage::GFU_Blue => RatFieldUsage::Blue,
+            GDALRATFieldUsage::GFU_Alpha => RatFieldUsage::Alpha,
+            GDALRATFieldUsage::GFU_RedMin => RatFieldUsage::RedMin,
+            GDALRATFieldUsage::GFU_GreenMin => RatFieldUsage::GreenMin,
+            GDALRATFieldUsage::GFU_BlueMin => RatFieldUsage::BlueMin,
+            GDALRATFieldUsage::GFU_AlphaMin => RatFieldUsage::AlphaMin,
+            GDALRATFieldUsage::GFU_RedMax => RatFieldUsage::RedMax,
+            GDALRATFieldUsage::GFU_GreenMax => RatFieldUsage::GreenMax,
+            GDALRATFieldUsage::GFU_BlueMax => RatFieldUsage::BlueMax,
+            GDALRATFieldUsage::GFU_AlphaMax => RatFieldUsage::AlphaMax,
+            _ => RatFieldUsage::Generic,
+        }
+    }
+
+    fn to_gdal(self) -> GDALRATFieldUsage::Type {
+        match self {
+            RatFieldUsage::Generic => GDALRATFieldUsage::GFU_Generic,
+            RatFieldUsage::PixelCount => GDALRATFieldUsage::GFU_PixelCount,
+            RatFieldUsage::Name => GDALRATFieldUsage::GFU_Name,
+            RatFieldUsage::Min => GDALRATFieldUsage::GFU_Min,
+            RatFieldUsage::Max => GDALRATFieldUsage::GFU_Max,
+            RatFieldUsage::MinMax => GDALRATFieldUsage::GFU_MinMax,
+            RatFieldUsage::Red => GDALRATFieldUsage::GFU_Red,
+            RatFieldUsage::Green => GDALRATFieldUsage::GFU_Green,
+            RatFieldUsage::Blue => GDALRATFieldUsage::GFU_Blue,
+            RatFieldUsage::Alpha => GDALRATFieldUsage::GFU_Alpha,
+            RatFieldUsage::RedMin => GDALRATFieldUsage::GFU_RedMin,
+            RatFieldUsage::GreenMin => GDALRATFieldUsage::GFU_GreenMin,
+            RatFieldUsage::BlueMin => GDALRATFieldUsage::GFU_BlueMin,
+            RatFieldUsage::AlphaMin => GDALRATFieldUsage::GFU_AlphaMin,
+            RatFieldUsage::RedMax => GDALRATFieldUsage::GFU_RedMax,
+            RatFieldUsage::GreenMax => GDALRATFieldUsage::GFU_GreenMax,
+            RatFieldUsage::BlueMax => GDALRATFieldUsage::GFU_BlueMax,
+            RatFieldUsage::AlphaMax => GDALRATFieldUsage::GFU_AlphaMax,
+        }
+    }
+}
+
+/// The definition of a raster attribute table column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RatColumn {
+    pub name: String,
+    pub field_type: RatFieldType,
+    pub usage: RatFieldUsage,
+}
+
+/// An owned raster attribute table, mapping pixel values of a band to attributes
+/// such as class names or colors.
+#[derive(Debug)]
+pub struct RasterAttributeTable {
+    c_rat: GDALRasterAttributeTableH,
+}
+
+impl RasterAttributeTable {
+    /// Create an empty raster attribute table.
+    pub fn new() -> Result<Self> {
+        let c_rat = unsafe { gdal_sys::GDALCreateRasterAttributeTable() };
+        if c_rat.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateRasterAttributeTable").into());
+        }
+        Ok(RasterAttributeTable { c_rat })
+    }
+
+    /// Create a copy of a raster attribute table owned by GDAL.
+    ///
+    /// # Safety
+    /// The caller must ensure that `c_rat` is a valid raster attribute table handle.
+    pub unsafe fn from_c_rat(c_rat: GDALRasterAttributeTableH) -> Result<Self> {
+        let c_rat = gdal_sys::GDALRATClone(c_rat);
+        if c_rat.is_null() {
+            return Err(_last_null_pointer_err("GDALRATClone").into());
+        }
+        Ok(RasterAttributeTable { c_rat })
+    }
+
+    /// Build a raster attribute table from the features of a vector layer.
+    ///
+    /// Each field becomes a generic column and each feature a row, starting from the
+    /// first feature of the layer. Only integer, real and
+    /// string fields are supported; 64-bit integer fields are stored as real columns.
+    /// Unset fields are stored as zero or as an empty string.
+    pub fn from_layer(layer: &Layer) -> Result<Self> {
+        let mut rat = RasterAttributeTable::new()?;
+        let mut columns = Vec::new();
+        for field in layer.defn().fields() {
+            let field_type = match field.field_type() {
+                OGRFieldType::OFTInteger => RatFieldType::Integer,
+                OGRFieldType::OFTInteger64 | OGRFieldType::OFTReal => RatFieldType::Real,
+                OGRFieldType::OFTString => RatFieldType::String,
+                field_type => {
+                    return Err(ErrorKind::UnhandledFieldType {
+                        field_type,
+                        method_name: "OGR_Fld_GetType",
+                    }
+                    .into())
+                }
+            };
+            let name = field.name();
+            rat.create_column(&name, field_type, RatFieldUsage::Generic)?;
+            columns.push((name, field_type));
+        }
+
+        for (row, feature) in layer.features().enumerate() {
+            for (col, (name, field_type)) in columns.iter().enumerate() {
+                if !feature.field_is_set(name)? {
+                    // unset fields keep the column's default value
+                    match field_type {
+                        RatFieldType::Integer => rat.set_value_as_int(row, col, 0),
+                        RatFieldType::Real => rat.set_value_as_double(row, col, 0.0),
+                        RatFieldType::String => rat.set_value_as_string(row, col, "")?,
+                    }
+                    continue;
+                }
+                match feature.field(name)? {
+                    FieldValue::IntegerValue(value) => rat.set_value_as_int(row, col, value),
+                    FieldValue::Integer64Value(value) => {
+                        rat.set_value_as_double(row, col, value as f64)
+                    }
+                    FieldValue::RealValue(value) => rat.set_value_as_double(row, col, value),
+                    FieldValue::StringValue(value) => rat.set_value_as_string(row, col, &value)?,
+                    #[cfg(feature = "datetime")]
+                    _ => {
+                        return Err(ErrorKind::UnsupportedDataType {
+                            data_type: gdal_sys::GDALDataType::GDT_Unknown,
+                        }
+                        .into())
+                    }
+                }
+            }
+        }
+        Ok(rat)
+    }
+
+    /// Write the table into a vector layer, creating one field per column and one
+    /// feature (without geometry) per row.
+    pub fn to_layer(&self, layer: &Layer) -> Result<()> {
+        let columns = self.columns();
+        for column in &columns {
+            layer.create_defn_fields(&[(&column.name, column.field_type.to_ogr())])?;
+        }
+
+        for row in 0..self.row_count() {
+            let feature = Feature::new(layer.defn())?;
+            for (col, column) in columns.iter().enumerate() {
+                match column.field_type {
+                    RatFieldType::Integer => {
+                        feature.set_field_integer(&column.name, self.value_as_int(row, col))?
+                    }
+                    RatFieldType::Real => {
+                        feature.set_field_double(&column.name, self.value_as_double(row, col))?
+                    }
+                    RatFieldType::String => {
+                        feature.set_field_string(&column.name, &self.value_as_string(row, col))?
+                    }
+                }
+            }
+            feature.create(layer)?;
+        }
+        Ok(())
+    }
+
+    /// Create a copy of this raster attribute table.
+    pub fn try_clone(&self) -> Result<Self> {
+        unsafe { RasterAttributeTable::from_c_rat(self.c_rat) }
+    }
+
+    pub fn c_rat(&self) -> GDALRasterAttributeTableH {
+        self.c_rat
+    }
+
+    pub fn column_count(&self) -> usize {
+        (unsafe { gdal_sys::GDALRATGetColumnCount(self.c_rat) }) as usize
+    }
+
+    pub fn row_count(&self) -> usize {
+        (unsafe { gdal_sys::GDALRATGetRowCount(self.c_rat) }) as usize
+    }
+
+    /// Resize the table to `row_count` rows.
+    pub fn set_row_count(&mut self, row_count: usize) {
+        unsafe { gdal_sys::GDALRATSetRowCount(self.c_rat, row_count as c_int) };
+    }
+
+    /// Get the definition of the column at index `col`.
+    pub fn column(&self, col: usize) -> Option<RatColumn> {
+        if col >= self.column_count() {
+            return None;
+        }
+        let col = col as c_int;
+        Some(RatColumn {
+            name: _string(unsafe { gdal_sys::GDALRATGetNameOfCol(self.c_rat, col) }),
+            field_type: RatFieldType::from_gdal(unsafe {
+                gdal_sys::GDALRATGetTypeOfCol(self.c_rat, col)
+            }),
+            usage: RatFieldUsage::from_gdal(unsafe {
+                gdal_sys::GDALRATGetUsageOfCol(self.c_rat, col)
+            }),
+        })
+    }
+
+    /// Get the definitions of all columns.
+    pub fn columns(&self) -> Vec<RatColumn> {
+        (0..self.column_count())
+            .filter_map(|col| self.column(col))
+            .collect()
+    }
+
+    /// Get the index of the first column with the given usage.
+    pub fn column_of_usage(&self, usage: RatFieldUsage) -> Option<usize> {
+        let col = unsafe { gdal_sys::GDALRATGetColOfUsage(self.c_rat, usage.to_gdal()) };
+        if col < 0 {
+            return None;
+        }
+        Some(col as usize)
+    }
+
+    /// Append a new column to the table.
+    pub fn create_column(
+        &mut self,
+        name: &str,
+        field_type: RatFieldType,
+        usage: RatFieldUsage,
+    ) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let rv = unsafe {
+            gdal_sys::GDALRATCreateColumn(
+                self.c_rat,
+                c_name.as_ptr(),
+                field_type.to_gdal(),
+                usage.to_gdal(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv).into());
+        }
+        Ok(())
+    }
+
+    /// Get the index of the row that contains the given pixel value.
+    pub fn row_of_value(&self, value: f64) -> Option<usize> {
+        let row = unsafe { gdal_sys::GDALRATGetRowOfValue(self.c_rat, value) };
+        if row < 0 {
+            return None;
+        }
+        Some(row as usize)
+    }
+
+    pub fn value_as_string(&self, row: usize, col: usize) -> String {
+        _string(unsafe {
+            gdal_sys::GDALRATGetValueAsString(self.c_rat, row as c_int, col as c_int)
+        })
+    }
+
+    pub fn value_as_int(&self, row: usize, col: usize) -> i32 {
+        unsafe { gdal_sys::GDALRATGetValueAsInt(self.c_rat, row as c_int, col as c_int) }
+    }
+
+    pub fn value_as_double(&self, row: usize, col: usize) -> f64 {
+        unsafe { gdal_sys::GDALRATGetValueAsDouble(self.c_rat, row as c_int, col as c_int) }
+    }
+
+    /// Set a cell value. Setting a value in the row just past the end appends a row.
+    pub fn set_value_as_string(&mut self, row: usize, col: usize, value: &str) -> Result<()> {
+        let c_value = CString::new(value)?;
+        unsafe {
+            gdal_sys::GDALRATSetValueAsString(
+                self.c_rat,
+                row as c_int,
+                col as c_int,
+                c_value.as_ptr(),
+            )
+        };
+        Ok(())
+    }
+
+    /// Set a cell value. Setting a value in the row just past the end appends a row.
+    pub fn set_value_as_int(&mut self, row: usize, col: usize, value: i32) {
+        unsafe { gdal_sys::GDALRATSetValueAsInt(self.c_rat, row as c_int, col as c_int, value) };
+    }
+
+    /// Set a cell value. Setting a value in the row just past the end appends a row.
+    pub fn set_value_as_double(&mut self, row: usize, col: usize, value: f64) {
+        unsafe { gdal_sys::GDALRATSetValueAsDouble(self.c_rat, row as c_int, col as c_int, value) };
+    }
+}
+
+impl Drop for RasterAttributeTable {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALDestroyRasterAttributeTable(self.c_rat) };
+    }
+}
@@ -1,9 +1,21 @@
 use crate::dataset::Dataset;
+use crate::errors::ErrorKind;
 use crate::metadata::Metadata;
-use crate::raster::ByteBuffer;
-use crate::Driver;
-use gdal_sys::GDALDataType;
+use crate::raster::{
+    auto_create_warped_vrt, create_warped, create_warped_vrt, gcps_to_geo_transform, rasterize,
+    rasterize_layers, reproject_with_progress, suggested_warp_output, warp, warp_with_progress,
+    BurnSource, ByteBuffer, ColorEntry, ColorInterpretation, ColorTable, DynBuffer, Gcp,
+    GdalDataTypeExt, GdalMaskFlags, Interleave, MergeAlgorithm, MultiBandBuffer,
+    RasterAttributeTable, RasterCreationOption, RasterIOExtraArg, RasterizeOptions, RatColumn,
+    RatFieldType, RatFieldUsage, ResampleAlg, Statistics, Transformer, WarpOptions,
+    WarpResampleAlg,
+};
+use crate::spatial_ref::SpatialRef;
+use crate::vector::{Feature, FieldValue, Geometry};
+use crate::{Driver, GeoTransform, ProgressStatus};
+use gdal_sys::{GDALDataType, OGRFieldType, OGRwkbGeometryType};
 use std::path::Path;
+use std::slice;
 
 #[cfg(feature = "ndarray")]
 use ndarray::arr2;
@@ -83,6 +95,626 @@ fn test_read_raster() {
     assert_eq!(buf.data, vec!(7, 7, 7, 10, 8, 12));
 }
 
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_read_raster_ex() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create_with_band_type::<f32>("", 4, 4, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    let raster = ByteBuffer::new((4, 4), (0..16).collect());
+    rb.write((0, 0), (4, 4), &raster).unwrap();
+
+    let average = RasterIOExtraArg::new(ResampleAlg::Average);
+    let rv = rb
+        .read_as_ex::<f32>((0, 0), (4, 4), (2, 2), &average)
+        .unwrap();
+    assert_eq!(rv.data, vec![2.5, 4.5, 10.5, 12.5]);
+
+    let mut buf = vec![0f32; 1];
+    let fractional = average.floating_point_window((1.0, 1.0, 2.0, 2.0));
+    rb.read_into_slice_ex((1, 1), (2, 2), (1, 1), &mut buf, &fractional)
+        .unwrap();
+    assert_eq!(buf, vec![7.5]);
+
+    let bilinear = RasterIOExtraArg::new(ResampleAlg::Bilinear);
+    let rv = rb
+        .read_as_ex::<f32>((0, 0), (4, 4), (8, 8), &bilinear)
+        .unwrap();
+    let nearest = rb.read_as::<f32>((0, 0), (4, 4), (8, 8)).unwrap();
+    assert_ne!(rv.data, nearest.data);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_statistics() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 4, 2, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    let raster = ByteBuffer::new((4, 2), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    rb.write((0, 0), (4, 2), &raster).unwrap();
+
+    assert_eq!(rb.get_statistics(false, false).unwrap(), None);
+    assert_eq!(rb.compute_min_max(false).unwrap(), (1.0, 8.0));
+
+    let stats = rb.compute_statistics(false).unwrap();
+    assert_eq!(stats.min, 1.0);
+    assert_eq!(stats.max, 8.0);
+    assert_eq!(stats.mean, 4.5);
+    assert!((stats.std_dev - 5.25f64.sqrt()).abs() < 1e-9);
+    assert_eq!(rb.get_statistics(false, false).unwrap(), Some(stats));
+
+    let stored = Statistics {
+        min: 0.0,
+        max: 10.0,
+        mean: 5.0,
+        std_dev: 1.0,
+    };
+    rb.set_statistics(&stored).unwrap();
+    assert_eq!(rb.get_statistics(false, false).unwrap(), Some(stored));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_histogram() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 4, 2, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    let raster = ByteBuffer::new((4, 2), vec![0, 1, 2, 3, 4, 5, 6, 100]);
+    rb.write((0, 0), (4, 2), &raster).unwrap();
+
+    let histogram = rb.histogram(4, -0.5, 7.5, false, false).unwrap();
+    assert_eq!(histogram.counts, vec![2, 2, 2, 1]);
+    assert_eq!(histogram.bucket_size(), 2.0);
+    assert_eq!(histogram.bucket_range(1), (1.5, 3.5));
+
+    let histogram = rb.histogram(4, -0.5, 7.5, true, false).unwrap();
+    assert_eq!(histogram.counts, vec![2, 2, 2, 2]);
+}
+
+#[test]
+fn test_color_interpretation() {
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let interps: Vec<_> = (1..=3)
+        .map(|band| dataset.rasterband(band).unwrap().color_interpretation())
+        .collect();
+    assert_eq!(
+        interps,
+        vec![
+            ColorInterpretation::RedBand,
+            ColorInterpretation::GreenBand,
+            ColorInterpretation::BlueBand
+        ]
+    );
+    assert_eq!(ColorInterpretation::RedBand.name(), "Red");
+    assert_eq!(
+        ColorInterpretation::from_name("Palette").unwrap(),
+        ColorInterpretation::PaletteIndex
+    );
+    assert!(dataset.rasterband(1).unwrap().color_table().is_none());
+}
+
+#[test]
+fn test_color_table() {
+    let mut color_table = ColorTable::color_ramp(
+        0,
+        &ColorEntry::rgba(0, 0, 0, 255),
+        4,
+        &ColorEntry::rgba(255, 128, 0, 255),
+    )
+    .unwrap();
+    assert_eq!(color_table.entry_count(), 5);
+    assert_eq!(
+        color_table.entry(2),
+        Some(ColorEntry::rgba(127, 64, 0, 255))
+    );
+    assert_eq!(color_table.entry(5), None);
+
+    color_table.set_entry(5, &ColorEntry::rgba(0, 0, 255, 0));
+    let entries: Vec<_> = color_table.entries().collect();
+    assert_eq!(entries.len(), 6);
+    assert_eq!(entries[0], ColorEntry::rgba(0, 0, 0, 255));
+    assert_eq!(entries[5], color_table.entry_as_rgba(5).unwrap());
+
+    let mut copy = color_table.try_clone().unwrap();
+    copy.set_entry(0, &ColorEntry::rgba(1, 2, 3, 4));
+    assert_eq!(copy.entry_count(), 6);
+    assert_eq!(color_table.entry(0), Some(ColorEntry::rgba(0, 0, 0, 255)));
+
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 4, 4, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    rb.set_color_interpretation(ColorInterpretation::PaletteIndex)
+        .unwrap();
+    rb.set_color_table(&color_table).unwrap();
+
+    let png = dataset
+        .create_copy(&Driver::get("PNG").unwrap(), "/vsimem/test_color_table.png")
+        .unwrap();
+    let rb = png.rasterband(1).unwrap();
+    assert_eq!(rb.color_interpretation(), ColorInterpretation::PaletteIndex);
+    let copied: Vec<_> = rb.color_table().unwrap().entries().collect();
+    assert_eq!(copied, entries);
+}
+
+fn land_cover_rat() -> RasterAttributeTable {
+    let mut rat = RasterAttributeTable::new().unwrap();
+    rat.create_column("Value", RatFieldType::Integer, RatFieldUsage::MinMax)
+        .unwrap();
+    rat.create_column("Class", RatFieldType::String, RatFieldUsage::Name)
+        .unwrap();
+    rat.create_column("Cover", RatFieldType::Real, RatFieldUsage::Generic)
+        .unwrap();
+    for (row, (class, cover)) in [("water", 0.25), ("forest", 0.5), ("urban", 0.25)]
+        .iter()
+        .enumerate()
+    {
+        rat.set_value_as_int(row, 0, row as i32 + 1);
+        rat.set_value_as_string(row, 1, class).unwrap();
+        rat.set_value_as_double(row, 2, *cover);
+    }
+    rat
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_raster_attribute_table() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 4, 4, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    assert!(rb.default_rat().is_none());
+    rb.set_default_rat(&land_cover_rat()).unwrap();
+
+    let rat = rb.default_rat().unwrap();
+    assert_eq!(rat.row_count(), 3);
+    assert_eq!(rat.column_count(), 3);
+    assert_eq!(
+        rat.column(1),
+        Some(RatColumn {
+            name: "Class".to_string(),
+            field_type: RatFieldType::String,
+            usage: RatFieldUsage::Name,
+        })
+    );
+    assert_eq!(rat.column(3), None);
+    assert_eq!(rat.column_of_usage(RatFieldUsage::Name), Some(1));
+    assert_eq!(rat.column_of_usage(RatFieldUsage::Red), None);
+
+    let row = rat.row_of_value(2.0).unwrap();
+    assert_eq!(rat.value_as_string(row, 1), "forest");
+    assert_eq!(rat.value_as_double(row, 2), 0.5);
+    assert_eq!(rat.value_as_int(row, 0), 2);
+
+    let mut copy = rat.try_clone().unwrap();
+    copy.set_row_count(5);
+    assert_eq!(copy.row_count(), 5);
+    assert_eq!(rat.row_count(), 3);
+    assert_eq!(rat.row_of_value(7.0), None);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_raster_attribute_table_layer() {
+    let rat = land_cover_rat();
+    let driver = Driver::get("Memory").unwrap();
+    let mut ds = driver.create_vector_only("").unwrap();
+    let layer = ds.create_layer_blank().unwrap();
+    rat.to_layer(&layer).unwrap();
+
+    let classes: Vec<_> = layer
+        .features()
+        .map(|f| f.field("Class").unwrap().into_string().unwrap())
+        .collect();
+    assert_eq!(classes, vec!["water", "forest", "urban"]);
+
+    let layer = ds.layer(0).unwrap();
+    let copy = RasterAttributeTable::from_layer(&layer).unwrap();
+    assert_eq!(copy.row_count(), 3);
+    let columns = copy.columns();
+    assert_eq!(columns.len(), 3);
+    assert_eq!(columns[0].field_type, RatFieldType::Integer);
+    assert_eq!(columns[1].field_type, RatFieldType::String);
+    assert_eq!(columns[2].field_type, RatFieldType::Real);
+    assert_eq!(columns[1].usage, RatFieldUsage::Generic);
+    assert_eq!(copy.value_as_string(2, 1), "urban");
+    assert_eq!(copy.value_as_double(0, 2), 0.25);
+
+    let feature = Feature::new(layer.defn()).unwrap();
+    feature.set_field_integer("Value", 4).unwrap();
+    feature.create(&layer).unwrap();
+    let copy = RasterAttributeTable::from_layer(&layer).unwrap();
+    assert_eq!(copy.row_count(), 4);
+    assert_eq!(copy.value_as_int(3, 0), 4);
+    assert_eq!(copy.value_as_string(3, 1), "");
+    assert_eq!(copy.value_as_double(3, 2), 0.0);
+}
+
+#[test]
+fn test_mask_flags() {
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    assert_eq!(rb.mask_flags(), GdalMaskFlags::GMF_ALL_VALID);
+    let mask = rb.mask_band().unwrap();
+    assert_eq!(mask.band_type(), GDALDataType::GDT_Byte);
+    assert_eq!(mask.size(), rb.size());
+
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 2, 1, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    rb.set_no_data_value(3.0).unwrap();
+    rb.write((0, 0), (2, 1), &ByteBuffer::new((2, 1), vec![3, 4]))
+        .unwrap();
+    assert_eq!(rb.mask_flags(), GdalMaskFlags::GMF_NODATA);
+    let mask = rb.mask_band().unwrap().read_band_as::<u8>().unwrap();
+    assert_eq!(mask.data, vec![0, 255]);
+}
+
+#[test]
+fn test_create_mask_band() {
+    let driver = Driver::get("GTiff").unwrap();
+    let dataset = driver
+        .create("/vsimem/test_create_mask_band.tif", 2, 2, 3)
+        .unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    rb.create_mask_band(true).unwrap();
+
+    for band in 1..=3 {
+        let flags = dataset.rasterband(band).unwrap().mask_flags();
+        assert!(flags.contains(GdalMaskFlags::GMF_PER_DATASET));
+        assert!(!flags.contains(GdalMaskFlags::GMF_ALL_VALID));
+    }
+
+    let mask = rb.mask_band().unwrap();
+    mask.write(
+        (0, 0),
+        (2, 2),
+        &ByteBuffer::new((2, 2), vec![0, 255, 255, 0]),
+    )
+    .unwrap();
+    let other_mask = dataset.rasterband(3).unwrap().mask_band().unwrap();
+    assert_eq!(
+        other_mask.read_band_as::<u8>().unwrap().data,
+        vec![0, 255, 255, 0]
+    );
+}
+
+#[test]
+fn test_blocks() {
+    let driver = Driver::get("GTiff").unwrap();
+    let options = [
+        RasterCreationOption {
+            key: "TILED",
+            value: "YES",
+        },
+        RasterCreationOption {
+            key: "BLOCKXSIZE",
+            value: "16",
+        },
+        RasterCreationOption {
+            key: "BLOCKYSIZE",
+            value: "16",
+        },
+    ];
+    let dataset = driver
+        .create_with_band_type_with_options::<u8>("/vsimem/test_blocks.tif", 20, 18, 1, &options)
+        .unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    assert_eq!(rb.block_size(), (16, 16));
+    assert_eq!(rb.block_count(), (2, 2));
+    assert_eq!(rb.block_window((1, 1)).unwrap(), ((16, 16), (4, 2)));
+
+    for y in 0..2 {
+        for x in 0..2 {
+            let (_, size) = rb.block_window((x, y)).unwrap();
+            let value = (y * 2 + x) as u8;
+            let buffer = ByteBuffer::new(size, vec![value; size.0 * size.1]);
+            rb.write_block((x, y), &buffer).unwrap();
+        }
+    }
+
+    let blocks: Vec<_> = rb.blocks::<u8>().map(|block| block.unwrap()).collect();
+    assert_eq!(blocks.len(), 4);
+    let expected = [
+        ((0, 0), (0, 0), (16, 16)),
+        ((1, 0), (16, 0), (4, 16)),
+        ((0, 1), (0, 16), (16, 2)),
+        ((1, 1), (16, 16), (4, 2)),
+    ];
+    for (value, ((block_index, window, buffer), expected)) in
+        blocks.iter().zip(expected.iter()).enumerate()
+    {
+        assert_eq!((*block_index, *window, buffer.size), *expected);
+        assert!(buffer.data.iter().all(|&v| v == value as u8));
+    }
+
+    let full = rb.read_band_as::<u8>().unwrap();
+    assert_eq!(full.data[15], 0);
+    assert_eq!(full.data[16], 1);
+    assert_eq!(full.data[20 * 17 + 19], 3);
+
+    let wrong_size = ByteBuffer::new((16, 16), vec![0; 256]);
+    assert!(matches!(
+        rb.write_block((1, 1), &wrong_size).unwrap_err().kind_ref(),
+        ErrorKind::BufferSizeMismatch { .. }
+    ));
+    assert!(matches!(
+        rb.block_window((2, 0)).unwrap_err().kind_ref(),
+        ErrorKind::InvalidBlockIndex { .. }
+    ));
+}
+
+#[test]
+#[cfg(feature = "complex")]
+fn test_complex_band() {
+    use crate::raster::Buffer;
+    use num_complex::Complex;
+
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver
+        .create_with_band_type::<Complex<f32>>("", 2, 1, 1)
+        .unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    assert_eq!(rb.band_type(), GDALDataType::GDT_CFloat32);
+
+    let data = vec![Complex::new(1.0, -1.0), Complex::new(3.0, 2.0)];
+    rb.write((0, 0), (2, 1), &Buffer::new((2, 1), data.clone()))
+        .unwrap();
+    assert_eq!(rb.read_band_as::<Complex<f32>>().unwrap().data, data);
+
+    let rv = rb.read_band_as::<Complex<i16>>().unwrap();
+    assert_eq!(rv.data, vec![Complex::new(1, -1), Complex::new(3, 2)]);
+}
+
+#[test]
+#[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+fn test_int64_band() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create_with_band_type::<i64>("", 2, 1, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    assert_eq!(rb.band_type(), GDALDataType::GDT_Int64);
+
+    let data = vec![i64::MIN, i64::MAX];
+    rb.write(
+        (0, 0),
+        (2, 1),
+        &crate::raster::Buffer::new((2, 1), data.clone()),
+    )
+    .unwrap();
+    assert_eq!(rb.read_band_as::<i64>().unwrap().data, data);
+}
+
+#[test]
+fn test_data_type_info() {
+    assert_eq!(GDALDataType::GDT_Byte.size_bytes(), 1);
+    assert_eq!(GDALDataType::GDT_Float64.size_bytes(), 8);
+    assert_eq!(GDALDataType::GDT_CInt16.size_bytes(), 4);
+
+    assert!(GDALDataType::GDT_UInt16.is_integer());
+    assert!(!GDALDataType::GDT_UInt16.is_signed());
+    assert!(GDALDataType::GDT_Int32.is_signed());
+    assert!(GDALDataType::GDT_Float32.is_floating());
+    assert!(GDALDataType::GDT_Float32.is_signed());
+    assert!(!GDALDataType::GDT_Float32.is_integer());
+    assert!(GDALDataType::GDT_CFloat64.is_complex());
+    assert!(!GDALDataType::GDT_Float64.is_complex());
+
+    assert_eq!(
+        GDALDataType::GDT_Byte.union(GDALDataType::GDT_UInt16),
+        GDALDataType::GDT_UInt16
+    );
+    assert_eq!(
+        GDALDataType::GDT_UInt16.union(GDALDataType::GDT_Int16),
+        GDALDataType::GDT_Int32
+    );
+    assert_eq!(
+        GDALDataType::GDT_Int16.union(GDALDataType::GDT_Float32),
+        GDALDataType::GDT_Float32
+    );
+    assert_eq!(GDALDataType::GDT_CFloat32.name(), "CFloat32");
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_read_native() {
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    let buffer = rb.read_native((20, 30), (2, 3), (2, 3)).unwrap();
+    assert_eq!(buffer.size(), (2, 3));
+    assert_eq!(buffer.data_type(), GDALDataType::GDT_Byte);
+    match buffer {
+        DynBuffer::U8(buffer) => assert_eq!(buffer.data, vec![7, 7, 7, 10, 8, 12]),
+        _ => panic!("expected a byte buffer"),
+    }
+
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create_with_band_type::<f64>("", 2, 1, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    rb.write((0, 0), (2, 1), &ByteBuffer::new((2, 1), vec![1, 2]))
+        .unwrap();
+    match rb.read_native((0, 0), (2, 1), (2, 1)).unwrap() {
+        DynBuffer::F64(buffer) => assert_eq!(buffer.data, vec![1.0, 2.0]),
+        _ => panic!("expected a float64 buffer"),
+    }
+}
+
+#[test]
+fn test_invalid_window() {
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    let (x, y) = rb.size();
+    assert!(rb.read_as::<u8>((0, 0), (x, y), (x, y)).is_ok());
+    assert!(matches!(
+        rb.read_as::<u8>((-1, 0), (2, 2), (2, 2))
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::InvalidWindow { .. }
+    ));
+    assert!(matches!(
+        rb.read_as::<u8>((0, 1), (x, y), (x, y))
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::InvalidWindow { .. }
+    ));
+
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 2, 2, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    let buffer = ByteBuffer::new((2, 2), vec![0; 3]);
+    assert_eq!(
+        rb.write((0, 0), (2, 2), &buffer).unwrap_err().kind_ref(),
+        &ErrorKind::BufferLengthMismatch {
+            len: 3,
+            expected: 4
+        }
+    );
+}
+
+#[test]
+fn test_read_dataset_bands() {
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let rv = dataset
+        .read_as::<u8>((20, 30), (2, 3), (2, 3), &[1, 2, 3], Interleave::Band)
+        .unwrap();
+    assert_eq!(rv.size, (2, 3));
+    assert_eq!(rv.band_count, 3);
+    assert_eq!(rv.data.len(), 18);
+    assert_eq!(&rv.data[..6], &[7, 7, 7, 10, 8, 12]);
+    for band in 1..=3 {
+        let single = dataset
+            .rasterband(band)
+            .unwrap()
+            .read_as::<u8>((20, 30), (2, 3), (2, 3))
+            .unwrap();
+        let offset = (band as usize - 1) * 6;
+        assert_eq!(&rv.data[offset..offset + 6], single.data.as_slice());
+    }
+
+    let interleaved = dataset
+        .read_as::<u8>((20, 30), (2, 3), (2, 3), &[1, 2, 3], Interleave::Pixel)
+        .unwrap();
+    for pixel in 0..6 {
+        for band in 0..3 {
+            assert_eq!(
+                interleaved.data[pixel * 3 + band],
+                rv.data[band * 6 + pixel]
+            );
+        }
+    }
+}
+
+#[test]
+fn test_read_dataset_band_subset() {
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let rv = dataset
+        .read_as::<u8>((20, 30), (2, 3), (2, 3), &[3, 1], Interleave::Band)
+        .unwrap();
+    let third = dataset
+        .rasterband(3)
+        .unwrap()
+        .read_as::<u8>((20, 30), (2, 3), (2, 3))
+        .unwrap();
+    assert_eq!(&rv.data[..6], third.data.as_slice());
+    assert_eq!(&rv.data[6..], &[7, 7, 7, 10, 8, 12]);
+    assert!(dataset
+        .read_as::<u8>((20, 30), (2, 3), (2, 3), &[4], Interleave::Band)
+        .is_err());
+}
+
+#[test]
+fn test_write_dataset_bands() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 2, 2, 3).unwrap();
+    let buffer = MultiBandBuffer::new(
+        (2, 2),
+        3,
+        Interleave::Pixel,
+        vec![1u8, 10, 100, 2, 20, 200, 3, 30, 130, 4, 40, 140],
+    );
+    dataset.write((0, 0), (2, 2), &[1, 2, 3], &buffer).unwrap();
+    let rb = dataset.rasterband(2).unwrap();
+    assert_eq!(rb.read_band_as::<u8>().unwrap().data, vec![10, 20, 30, 40]);
+    let rv = dataset
+        .read_as::<u8>((0, 0), (2, 2), (2, 2), &[3, 1], Interleave::Band)
+        .unwrap();
+    assert_eq!(rv.data, vec![100, 200, 130, 140, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_dataset_io_errors() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 2, 2, 3).unwrap();
+
+    let mut buf = vec![0u8; 8];
+    assert!(matches!(
+        dataset
+            .read_into_slice(
+                (0, 0),
+                (2, 2),
+                (2, 2),
+                &[1, 2, 3],
+                Interleave::Band,
+                &mut buf
+            )
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::BufferLengthMismatch {
+            len: 8,
+            expected: 12
+        }
+    ));
+    assert!(matches!(
+        dataset
+            .read_into_slice((1, 0), (2, 2), (2, 2), &[1, 2], Interleave::Band, &mut buf)
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::InvalidWindow { .. }
+    ));
+
+    let buffer = MultiBandBuffer::new((2, 2), 2, Interleave::Band, vec![0u8; 8]);
+    assert!(matches!(
+        dataset
+            .write((0, 0), (2, 2), &[1, 2, 3], &buffer)
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::BandCountMismatch {
+            band_count: 2,
+            expected: 3
+        }
+    ));
+    assert!(matches!(
+        dataset
+            .write((0, -1), (2, 2), &[1, 2], &buffer)
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::InvalidWindow { .. }
+    ));
+    let short = MultiBandBuffer::new((2, 2), 2, Interleave::Band, vec![0u8; 6]);
+    assert!(matches!(
+        dataset
+            .write((0, 0), (2, 2), &[1, 2], &short)
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::BufferLengthMismatch { .. }
+    ));
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_read_dataset_bands_as_array() {
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let array = dataset
+        .read_as_array::<u8>((20, 30), (2, 3), (2, 3), &[1, 2, 3], Interleave::Band)
+        .unwrap();
+    assert_eq!(array.dim(), (3, 3, 2));
+    assert_eq!(array[[0, 1, 1]], 10);
+    let interleaved = dataset
+        .read_as_array::<u8>((20, 30), (2, 3), (2, 3), &[1, 2, 3], Interleave::Pixel)
+        .unwrap();
+    assert_eq!(interleaved.dim(), (3, 2, 3));
+    for band in 0..3 {
+        assert_eq!(interleaved[[2, 1, band]], array[[band, 2, 1]]);
+    }
+}
+
 #[test]
 fn test_write_raster() {
     let driver = Driver::get("MEM").unwrap();
@@ -182,16 +814,254 @@ fn test_create_copy() {
     assert_eq!(copy.raster_count(), 3);
 }
 
+#[test]
+fn test_create_with_band_type_with_options() {
+    let driver = Driver::get("GTiff").unwrap();
+    let options = [
+        RasterCreationOption {
+            key: "TILED",
+            value: "YES",
+        },
+        RasterCreationOption {
+            key: "BLOCKXSIZE",
+            value: "128",
+        },
+        RasterCreationOption {
+            key: "BLOCKYSIZE",
+            value: "64",
+        },
+        RasterCreationOption {
+            key: "COMPRESS",
+            value: "LZW",
+        },
+    ];
+    let dataset = driver
+        .create_with_band_type_with_options::<u8>(
+            "/vsimem/test_create_options.tif",
+            256,
+            256,
+            1,
+            &options,
+        )
+        .unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    assert_eq!(rb.block_size(), (128, 64));
+    assert_eq!(
+        dataset.metadata_item("COMPRESSION", "IMAGE_STRUCTURE"),
+        Some("LZW".to_string())
+    );
+}
+
+#[test]
+fn test_create_with_invalid_options() {
+    let driver = Driver::get("GTiff").unwrap();
+    let options = [RasterCreationOption {
+        key: "COMPRESS",
+        value: "NOT_A_COMPRESSION",
+    }];
+    let result = driver.create_with_band_type_with_options::<u8>(
+        "/vsimem/test_invalid_options.tif",
+        10,
+        10,
+        1,
+        &options,
+    );
+    match result.unwrap_err().kind_ref() {
+        ErrorKind::InvalidCreationOptions { driver, .. } => assert_eq!(driver, "GTiff"),
+        kind => panic!("unexpected error {:?}", kind),
+    }
+}
+
+#[test]
+fn test_create_copy_with_options() {
+    let driver = Driver::get("GTiff").unwrap();
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let options = [RasterCreationOption {
+        key: "COMPRESS",
+        value: "DEFLATE",
+    }];
+    let copy = dataset
+        .create_copy_with_options(&driver, "/vsimem/test_create_copy_options.tif", &options)
+        .unwrap();
+    assert_eq!(copy.raster_size(), (100, 50));
+    assert_eq!(
+        copy.metadata_item("COMPRESSION", "IMAGE_STRUCTURE"),
+        Some("DEFLATE".to_string())
+    );
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_create_copy_with_progress() {
+    let driver = Driver::get("GTiff").unwrap();
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let mut steps = Vec::new();
+    let mut progress = |complete: f64, _: &str| {
+        steps.push(complete);
+        ProgressStatus::Continue
+    };
+    dataset
+        .create_copy_with_progress(
+            &driver,
+            "/vsimem/test_create_copy_progress.tif",
+            &[],
+            &mut progress,
+        )
+        .unwrap();
+    assert!(!steps.is_empty());
+    assert!(steps.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(*steps.last().unwrap(), 1.0);
+}
+
+#[test]
+fn test_create_copy_with_progress_abort() {
+    let driver = Driver::get("GTiff").unwrap();
+    let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let mut calls = 0;
+    let mut progress = |_: f64, _: &str| {
+        calls += 1;
+        ProgressStatus::Abort
+    };
+    let result = dataset.create_copy_with_progress(
+        &driver,
+        "/vsimem/test_create_copy_abort.tif",
+        &[],
+        &mut progress,
+    );
+    assert!(result.is_err());
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_add_band() {
+    let driver = Driver::get("MEM").unwrap();
+    let mut dataset = driver.create("", 20, 10, 1).unwrap();
+    let rb = dataset.add_band::<f32>(&[]).unwrap();
+    assert_eq!(rb.band_type(), GDALDataType::GDT_Float32);
+    assert_eq!(rb.size(), (20, 10));
+    assert_eq!(dataset.raster_count(), 2);
+    assert_eq!(
+        dataset.rasterband(1).unwrap().band_type(),
+        GDALDataType::GDT_Byte
+    );
+
+    let mut png = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    assert!(png.add_band::<u8>(&[]).is_err());
+}
+
+#[test]
+fn test_add_raw_vrt_band() {
+    use std::fs;
+
+    let raw_path = std::env::temp_dir().join("gdal_test_add_raw_vrt_band.raw");
+    fs::write(&raw_path, (0..20u8).collect::<Vec<_>>()).unwrap();
+
+    let driver = Driver::get("VRT").unwrap();
+    let mut dataset = driver.create("", 5, 4, 0).unwrap();
+    let source_filename = raw_path.to_string_lossy();
+    let options = [
+        RasterCreationOption {
+            key: "subClass",
+            value: "VRTRawRasterBand",
+        },
+        RasterCreationOption {
+            key: "SourceFilename",
+            value: &source_filename,
+        },
+    ];
+    let data = dataset
+        .add_band::<u8>(&options)
+        .unwrap()
+        .read_band_as::<u8>()
+        .unwrap();
+    fs::remove_file(&raw_path).unwrap();
+    assert_eq!(data.data, (0..20u8).collect::<Vec<_>>());
+}
+
 #[test]
 #[allow(clippy::float_cmp)]
 fn test_geo_transform() {
     let driver = Driver::get("MEM").unwrap();
     let dataset = driver.create("", 20, 10, 1).unwrap();
-    let transform = [0., 1., 0., 0., 0., 1.];
+    let transform = GeoTransform::new([0., 1., 0., 0., 0., 1.]);
     assert!(dataset.set_geo_transform(&transform).is_ok());
     assert_eq!(dataset.geo_transform().unwrap(), transform);
 }
 
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_geo_transform_helpers() {
+    let transform = GeoTransform::new([10., 0.5, 0., 50., 0., -0.25]);
+    assert_eq!(transform.x_origin(), 10.);
+    assert_eq!(transform.pixel_width(), 0.5);
+    assert_eq!(transform.y_origin(), 50.);
+    assert_eq!(transform.pixel_height(), -0.25);
+    assert_eq!(transform.row_rotation(), 0.);
+    assert_eq!(transform.column_rotation(), 0.);
+    assert_eq!(transform[1], 0.5);
+    assert_eq!(GeoTransform::default().apply(3., 4.), (3., 4.));
+
+    assert_eq!(transform.apply(20., 10.), (20., 47.5));
+    let inverse = transform.invert().unwrap();
+    assert_eq!(inverse.apply(20., 47.5), (20., 10.));
+    assert_eq!(transform.compose(&inverse), GeoTransform::default());
+    assert!(GeoTransform::new([0.; 6]).invert().is_none());
+
+    assert_eq!(transform.bounds((20, 10)), (10., 47.5, 20., 50.));
+    assert_eq!(
+        transform.window((11.2, 48., 12., 49.)),
+        Some(((2, 4), (2, 4)))
+    );
+
+    let shifted = GeoTransform::new([1., 1., 0., 2., 0., 1.]).compose(&transform);
+    assert_eq!(shifted.apply(0., 0.), transform.apply(1., 2.));
+}
+
+fn test_gcps_list() -> Vec<Gcp> {
+    [(0., 0.), (20., 0.), (0., 10.), (20., 10.)]
+        .iter()
+        .enumerate()
+        .map(|(i, &(pixel, line))| Gcp {
+            id: (i + 1).to_string(),
+            info: "".to_string(),
+            pixel,
+            line,
+            x: 10. + pixel * 0.5,
+            y: 50. - line * 0.25,
+            z: 0.,
+        })
+        .collect()
+}
+
+#[test]
+fn test_gcps() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 20, 10, 1).unwrap();
+    assert!(dataset.gcps().is_empty());
+    assert_eq!(dataset.gcp_projection(), None);
+
+    let gcps = test_gcps_list();
+    let srs = SpatialRef::from_epsg(4326).unwrap();
+    dataset.set_gcps(&gcps, &srs).unwrap();
+    assert_eq!(dataset.gcps(), gcps);
+    let projection = SpatialRef::from_wkt(&dataset.gcp_projection().unwrap()).unwrap();
+    assert_eq!(projection, srs);
+}
+
+#[test]
+fn test_gcps_to_geo_transform() {
+    let transform = gcps_to_geo_transform(&test_gcps_list(), false)
+        .unwrap()
+        .unwrap();
+    let expected = [10., 0.5, 0., 50., 0., -0.25];
+    for (a, b) in transform.coefficients().iter().zip(expected.iter()) {
+        assert!((a - b).abs() < 1e-9);
+    }
+
+    let too_few = &test_gcps_list()[..1];
+    assert_eq!(gcps_to_geo_transform(too_few, true).unwrap(), None);
+}
+
 #[test]
 fn test_get_driver_by_name() {
     let missing_driver = Driver::get("wtf");
@@ -204,6 +1074,52 @@ fn test_get_driver_by_name() {
     assert_eq!(driver.long_name(), "GeoTIFF");
 }
 
+#[test]
+fn test_driver_iteration() {
+    let count = Driver::count();
+    assert!(count > 0);
+    assert_eq!(Driver::all().count() as isize, count);
+    assert!(Driver::all().any(|driver| driver.short_name() == "GTiff"));
+    assert!(Driver::get_by_index(count).is_err());
+}
+
+#[test]
+fn test_driver_capabilities() {
+    let driver = Driver::get("GTiff").unwrap();
+    assert!(driver.can_create());
+    assert!(driver.can_create_copy());
+    assert!(driver.is_raster());
+    assert!(!driver.is_vector());
+    assert!(driver.supports_virtual_io());
+    assert!(driver.extensions().contains(&"tif".to_string()));
+
+    let driver = Driver::get("PNG").unwrap();
+    assert!(!driver.can_create());
+    assert!(driver.can_create_copy());
+
+    let driver = Driver::get("GeoJSON").unwrap();
+    assert!(driver.is_vector());
+    assert!(!driver.is_raster());
+}
+
+#[test]
+fn test_driver_creation_options() {
+    let driver = Driver::get("GTiff").unwrap();
+    let options = driver.creation_options().unwrap();
+    let compress = options.iter().find(|o| o.name == "COMPRESS").unwrap();
+    assert_eq!(compress.option_type, "string-select");
+    assert!(compress.values.contains(&"LZW".to_string()));
+    let tiled = options.iter().find(|o| o.name == "TILED").unwrap();
+    assert_eq!(tiled.option_type, "boolean");
+
+    let driver = Driver::get("MEM").unwrap();
+    assert!(driver
+        .creation_options()
+        .unwrap()
+        .iter()
+        .all(|o| !o.name.is_empty()));
+}
+
 #[test]
 fn test_read_raster_as() {
     let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
@@ -243,6 +1159,57 @@ fn test_read_raster_as_array() {
     assert_eq!(rb.band_type(), GDALDataType::GDT_Byte);
 }
 
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_write_array() {
+    use ndarray::s;
+
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 3, 2, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+
+    let data = arr2(&[[1u8, 2, 3], [4, 5, 6]]);
+    rb.write_array((0, 0), (3, 2), data.view()).unwrap();
+    assert_eq!(
+        rb.read_as_array::<u8>((0, 0), (3, 2), (3, 2)).unwrap(),
+        data
+    );
+
+    // non-contiguous views: a transposed array and a slice with a step
+    let transposed = arr2(&[[10u8, 40], [20, 50], [30, 60]]);
+    rb.write_array((0, 0), (3, 2), transposed.t()).unwrap();
+    assert_eq!(
+        rb.read_as_array::<u8>((0, 0), (3, 2), (3, 2)).unwrap(),
+        transposed.t()
+    );
+
+    let wide = arr2(&[[7u8, 0, 8, 0, 9, 0]]);
+    rb.write_array((0, 1), (3, 1), wide.slice(s![.., ..;2]))
+        .unwrap();
+    assert_eq!(
+        rb.read_as_array::<u8>((0, 1), (3, 1), (3, 1)).unwrap(),
+        arr2(&[[7, 8, 9]])
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn test_write_array_invalid_window() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 3, 2, 1).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    let data = arr2(&[[1u8, 2, 3], [4, 5, 6]]);
+    let err = rb.write_array((1, 0), (3, 2), data.view()).unwrap_err();
+    assert_eq!(
+        err.kind_ref(),
+        &ErrorKind::InvalidWindow {
+            window: (1, 0),
+            window_size: (3, 2),
+            raster_size: (3, 2),
+        }
+    );
+}
+
 #[test]
 #[cfg(feature = "ndarray")]
 fn test_read_block_as_array() {
@@ -350,6 +1317,48 @@ fn test_get_offset() {
     assert_eq!(offset, Some(12.0));
 }
 
+#[test]
+fn test_set_scale_offset_unit_type() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 2, 2, 1).unwrap();
+    let rasterband = dataset.rasterband(1).unwrap();
+    rasterband.set_scale(0.01).unwrap();
+    rasterband.set_offset(-273.15).unwrap();
+    assert_eq!(rasterband.scale(), Some(0.01));
+    assert_eq!(rasterband.offset(), Some(-273.15));
+
+    assert_eq!(rasterband.unit_type(), "");
+    rasterband.set_unit_type("K").unwrap();
+    assert_eq!(rasterband.unit_type(), "K");
+}
+
+#[test]
+fn test_category_names() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 2, 2, 1).unwrap();
+    let rasterband = dataset.rasterband(1).unwrap();
+    assert!(rasterband.category_names().is_empty());
+    rasterband
+        .set_category_names(&["nodata", "water", "forest"])
+        .unwrap();
+    assert_eq!(
+        rasterband.category_names(),
+        vec!["nodata", "water", "forest"]
+    );
+}
+
+#[test]
+fn test_set_description() {
+    let driver = Driver::get("MEM").unwrap();
+    let dataset = driver.create("", 2, 2, 1).unwrap();
+    dataset.set_description("temperature").unwrap();
+    assert_eq!(dataset.description().unwrap(), "temperature");
+
+    let rasterband = dataset.rasterband(1).unwrap();
+    rasterband.set_description("surface").unwrap();
+    assert_eq!(rasterband.description().unwrap(), "surface");
+}
+
 #[test]
 fn test_get_default_scale() {
     let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
@@ -385,6 +1394,48 @@ fn test_get_default_offset() {
     }
 }
 
+#[test]
+fn test_build_overviews() {
+    let driver = Driver::get("GTiff").unwrap();
+    let source = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let mut dataset = source
+        .create_copy(&driver, "/vsimem/test_build_overviews.tif")
+        .unwrap();
+    assert_eq!(dataset.rasterband(1).unwrap().overview_count(), 0);
+
+    dataset.build_overviews("AVERAGE", &[2, 4], &[]).unwrap();
+    let rb = dataset.rasterband(1).unwrap();
+    assert_eq!(rb.overview_count(), 2);
+    let overview = rb.overview(0).unwrap();
+    assert_eq!(overview.size(), (50, 25));
+    assert_eq!(rb.overview(1).unwrap().size(), (25, 13));
+    assert!(rb.overview(2).is_err());
+
+    let full = rb.read_as::<u8>((0, 0), (2, 2), (2, 2)).unwrap();
+    let reduced = overview.read_as::<u8>((0, 0), (1, 1), (1, 1)).unwrap();
+    let mean = full.data.iter().map(|&v| v as f64).sum::<f64>() / 4.;
+    assert!((reduced.data[0] as f64 - mean).abs() <= 1.);
+}
+
+#[test]
+fn test_build_overviews_with_progress() {
+    let driver = Driver::get("GTiff").unwrap();
+    let source = Dataset::open(fixture!("tinymarble.png")).unwrap();
+    let mut dataset = source
+        .create_copy(&driver, "/vsimem/test_build_overviews_progress.tif")
+        .unwrap();
+    let mut calls = 0;
+    let mut progress = |_: f64, _: &str| {
+        calls += 1;
+        ProgressStatus::Continue
+    };
+    dataset
+        .build_overviews_with_progress("NEAREST", &[2], &[1, 2, 3], &mut progress)
+        .unwrap();
+    assert!(calls > 0);
+    assert_eq!(dataset.rasterband(1).unwrap().overview_count(), 1);
+}
+
 #[test]
 fn test_get_rasterband_size() {
     let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
@@ -409,3 +1460,359 @@ fn test_get_rasterband_actual_block_size() {
     let size = rasterband.actual_block_size((0, 40));
     assert_eq!(size.unwrap(), (100, 1));
 }
+
+fn warp_test_datasets(src_bands: isize, dst_bands: isize) -> (Dataset, Dataset) {
+    let driver = Driver::get("MEM").unwrap();
+    let wkt = SpatialRef::from_epsg(4326).unwrap().to_wkt().unwrap();
+
+    let src = driver.create("", 4, 4, src_bands).unwrap();
+    src.set_projection(&wkt).unwrap();
+    src.set_geo_transform(&[0., 1., 0., 4., 0., -1.].into())
+        .unwrap();
+    for band in 1..=src_bands {
+        let data = (0..16).map(|v| v as u8 + 10 * band as u8).collect();
+        let buffer = ByteBuffer { size: (4, 4), data };
+        src.rasterband(band)
+            .unwrap()
+            .write((0, 0), (4, 4), &buffer)
+            .unwrap();
+    }
+
+    // the source covers the central 4x4 pixels of the destination
+    let dst = driver.create("", 8, 8, dst_bands).unwrap();
+    dst.set_projection(&wkt).unwrap();
+    dst.set_geo_transform(&[-2., 1., 0., 6., 0., -1.].into())
+        .unwrap();
+    (src, dst)
+}
+
+#[test]
+fn test_warp() {
+    let (src, dst) = warp_test_datasets(1, 1);
+    let options = WarpOptions::new()
+        .resample_alg(WarpResampleAlg::NearestNeighbour)
+        .dst_no_data(&[255.])
+        .memory_limit(1024. * 1024.);
+    warp(&src, &dst, &options).unwrap();
+
+    let result = dst
+        .rasterband(1)
+        .unwrap()
+        .read_as::<u8>((0, 0), (8, 8), (8, 8))
+        .unwrap();
+    assert_eq!(result.data[0], 255);
+    assert_eq!(result.data[63], 255);
+    assert_eq!(result.data[2 * 8 + 2], 10);
+    assert_eq!(result.data[5 * 8 + 5], 25);
+}
+
+#[test]
+fn test_warp_with_progress() {
+    let (src, dst) = warp_test_datasets(1, 1);
+    let mut steps = Vec::new();
+    let mut progress = |complete: f64, _: &str| {
+        steps.push(complete);
+        ProgressStatus::Continue
+    };
+    warp_with_progress(&src, &dst, &WarpOptions::new(), &mut progress).unwrap();
+    assert!(!steps.is_empty());
+    assert!(steps.windows(2).all(|w| w[0] <= w[1]));
+
+    let (src, dst) = warp_test_datasets(1, 1);
+    let mut calls = 0;
+    let mut progress = |_: f64, _: &str| {
+        calls += 1;
+        ProgressStatus::Abort
+    };
+    assert!(warp_with_progress(&src, &dst, &WarpOptions::new(), &mut progress).is_err());
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_reproject_with_progress() {
+    let (src, dst) = warp_test_datasets(1, 1);
+    let mut calls = 0;
+    let mut progress = |_: f64, _: &str| {
+        calls += 1;
+        ProgressStatus::Continue
+    };
+    reproject_with_progress(&src, &dst, &mut progress).unwrap();
+    assert!(calls > 0);
+
+    let (src, dst) = warp_test_datasets(1, 1);
+    let mut calls = 0;
+    let mut progress = |_: f64, _: &str| {
+        calls += 1;
+        ProgressStatus::Abort
+    };
+    assert!(reproject_with_progress(&src, &dst, &mut progress).is_err());
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_warp_band_mapping() {
+    let (src, dst) = warp_test_datasets(2, 1);
+    let options = WarpOptions::new()
+        .band_mapping(&[2], &[1])
+        .multithreaded(true);
+    warp(&src, &dst, &options).unwrap();
+
+    let result = dst
+        .rasterband(1)
+        .unwrap()
+        .read_as::<u8>((2, 2), (4, 4), (4, 4))
+        .unwrap();
+    let expected: Vec<u8> = (20..36).collect();
+    assert_eq!(result.data, expected);
+}
+
+#[test]
+fn test_warp_invalid_options() {
+    let (src, dst) = warp_test_datasets(2, 2);
+    let options = WarpOptions::new().band_mapping(&[1, 2], &[1]);
+    assert!(matches!(
+        warp(&src, &dst, &options).unwrap_err().kind_ref(),
+        ErrorKind::InvalidWarpOptions { .. }
+    ));
+
+    let options = WarpOptions::new().src_no_data(&[0., 0., 0.]);
+    assert!(warp(&src, &dst, &options).is_err());
+}
+
+#[test]
+fn test_suggested_warp_output() {
+    let (src, _) = warp_test_datasets(1, 1);
+    let dst_srs = SpatialRef::from_epsg(3857).unwrap();
+    let output = suggested_warp_output(&src, &dst_srs).unwrap();
+    assert!(output.size.0 >= 4 && output.size.1 >= 4);
+    let (min_x, min_y, max_x, max_y) = output.extent;
+    assert!(min_x.abs() < 1.);
+    assert!(min_y.abs() < 1.);
+    assert!((max_x - 445_277.96).abs() < 1.);
+    assert!((max_y - 445_640.11).abs() < 1.);
+    assert!((output.geo_transform[0] - min_x).abs() < 1e-6);
+    assert!((output.geo_transform[3] - max_y).abs() < 1e-6);
+    let (res_x, res_y) = output.resolution();
+    assert!((res_x * output.size.0 as f64 - (max_x - min_x)).abs() < 1e-6);
+    assert!(res_y > 0.);
+}
+
+#[test]
+fn test_create_warped() {
+    let (src, _) = warp_test_datasets(2, 2);
+    let dst_srs = SpatialRef::from_epsg(3857).unwrap();
+    let driver = Driver::get("MEM").unwrap();
+    let options = WarpOptions::new().dst_no_data(&[255.]);
+    let dst = create_warped(&src, &dst_srs, &driver, "", &options, &[]).unwrap();
+
+    let output = suggested_warp_output(&src, &dst_srs).unwrap();
+    assert_eq!(dst.raster_count(), 2);
+    assert_eq!(dst.raster_size(), output.size);
+    assert_eq!(dst.geo_transform().unwrap(), output.geo_transform);
+    let rb = dst.rasterband(2).unwrap();
+    assert_eq!(rb.no_data_value(), Some(255.));
+    let data = rb.read_band_as::<u8>().unwrap().data;
+    assert_eq!(data[0], 20);
+    assert_eq!(*data.last().unwrap(), 35);
+}
+
+#[test]
+fn test_create_warped_without_bands() {
+    let (src, _) = warp_test_datasets(0, 0);
+    let dst_srs = SpatialRef::from_epsg(3857).unwrap();
+    let driver = Driver::get("MEM").unwrap();
+    let result = create_warped(&src, &dst_srs, &driver, "", &WarpOptions::new(), &[]);
+    assert!(matches!(
+        result.err().unwrap().kind_ref(),
+        ErrorKind::InvalidWarpOptions { .. }
+    ));
+}
+
+#[test]
+fn test_create_warped_vrt() {
+    let (src, _) = warp_test_datasets(1, 1);
+    let dst_srs = SpatialRef::from_epsg(3857).unwrap();
+    let output = suggested_warp_output(&src, &dst_srs).unwrap();
+
+    let vrt = create_warped_vrt(&src, &dst_srs, &WarpOptions::new()).unwrap();
+    assert_eq!(vrt.driver().short_name(), "VRT");
+    assert_eq!(vrt.raster_size(), output.size);
+    assert_eq!(vrt.geo_transform().unwrap(), output.geo_transform);
+    assert!(SpatialRef::from_wkt(&vrt.projection()).unwrap() == dst_srs);
+    let data = vrt
+        .rasterband(1)
+        .unwrap()
+        .read_band_as::<u8>()
+        .unwrap()
+        .data;
+    assert_eq!(data[0], 10);
+    assert_eq!(*data.last().unwrap(), 25);
+
+    let auto_vrt =
+        auto_create_warped_vrt(&src, &dst_srs, WarpResampleAlg::NearestNeighbour, 0.).unwrap();
+    assert_eq!(auto_vrt.raster_size(), output.size);
+    let auto_data = auto_vrt
+        .rasterband(1)
+        .unwrap()
+        .read_band_as::<u8>()
+        .unwrap()
+        .data;
+    assert_eq!(auto_data, data);
+
+    let pixel = auto_vrt
+        .rasterband(1)
+        .unwrap()
+        .read_as::<u8>((0, 0), (1, 1), (1, 1))
+        .unwrap();
+    assert_eq!(pixel.data, vec![10]);
+}
+
+#[test]
+fn test_transformer() {
+    let (src, dst) = warp_test_datasets(1, 1);
+    let wgs84 = SpatialRef::from_epsg(4326).unwrap();
+
+    let transformer = Transformer::with_dst_srs(&src, &wgs84, &[] as &[&str]).unwrap();
+    let (mut x, mut y, mut z) = (vec![0.5, 4.], vec![0.5, 4.], vec![0., 0.]);
+    let success = transformer
+        .transform_coords(&mut x, &mut y, &mut z)
+        .unwrap();
+    assert_eq!(success, vec![true, true]);
+    assert!((x[0] - 0.5).abs() < 1e-9 && (y[0] - 3.5).abs() < 1e-9);
+    assert!((x[1] - 4.).abs() < 1e-9 && y[1].abs() < 1e-9);
+
+    let success = transformer
+        .transform_coords_inverse(&mut x, &mut y, &mut z)
+        .unwrap();
+    assert_eq!(success, vec![true, true]);
+    assert!((x[0] - 0.5).abs() < 1e-9 && (y[0] - 0.5).abs() < 1e-9);
+
+    let transformer = Transformer::new(&src, &dst, &[] as &[&str])
+        .unwrap()
+        .approximate(0.125)
+        .unwrap();
+    let (mut x, mut y, mut z) = ([0.], [0.], [0.]);
+    let success = transformer
+        .transform_coords(&mut x, &mut y, &mut z)
+        .unwrap();
+    assert!(success[0]);
+    assert!((x[0] - 2.).abs() < 1e-6 && (y[0] - 2.).abs() < 1e-6);
+
+    assert!(matches!(
+        transformer
+            .transform_coords(&mut [0., 1.], &mut [0.], &mut [0.])
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::BufferLengthMismatch { .. }
+    ));
+}
+
+fn rasterize_test_dataset() -> Dataset {
+    let dataset = Driver::get("MEM").unwrap().create("", 10, 10, 1).unwrap();
+    dataset
+        .set_geo_transform(&[0., 1., 0., 10., 0., -1.].into())
+        .unwrap();
+    dataset
+}
+
+fn burned(dataset: &Dataset) -> Vec<u8> {
+    dataset
+        .rasterband(1)
+        .unwrap()
+        .read_band_as::<u8>()
+        .unwrap()
+        .data
+}
+
+#[test]
+fn test_rasterize() {
+    let dataset = rasterize_test_dataset();
+    let square = Geometry::bbox(2., 2., 6., 6.).unwrap();
+    let line = Geometry::from_wkt("LINESTRING (0 9.5, 10 9.5)").unwrap();
+    rasterize(
+        &dataset,
+        &[1],
+        &[square.clone(), line],
+        &[1., 2.],
+        &RasterizeOptions::new(),
+    )
+    .unwrap();
+    let data = burned(&dataset);
+    assert_eq!(data.iter().filter(|&&v| v == 1).count(), 16);
+    assert_eq!(data.iter().filter(|&&v| v == 2).count(), 10);
+    assert_eq!(data[4 * 10 + 2], 1);
+    assert_eq!(data[4 * 10 + 1], 0);
+
+    let options = RasterizeOptions::new().merge_algorithm(MergeAlgorithm::Add);
+    rasterize(&dataset, &[1], &[square], &[3.], &options).unwrap();
+    assert_eq!(burned(&dataset)[4 * 10 + 2], 4);
+
+    let dataset = rasterize_test_dataset();
+    let square = Geometry::bbox(2.7, 2.7, 5.3, 5.3).unwrap();
+    let options = RasterizeOptions::new();
+    rasterize(&dataset, &[1], slice::from_ref(&square), &[1.], &options).unwrap();
+    assert_eq!(burned(&dataset).iter().filter(|&&v| v == 1).count(), 4);
+    let options = options.all_touched(true);
+    rasterize(&dataset, &[1], &[square], &[1.], &options).unwrap();
+    assert_eq!(burned(&dataset).iter().filter(|&&v| v == 1).count(), 16);
+
+    assert!(matches!(
+        rasterize(&dataset, &[1], &[], &[1.], &options)
+            .unwrap_err()
+            .kind_ref(),
+        ErrorKind::InvalidBurnValueCount { count: 1, .. }
+    ));
+}
+
+#[test]
+fn test_rasterize_z() {
+    let dataset = rasterize_test_dataset();
+    let polygon = Geometry::from_wkt("POLYGON Z ((2 2 7, 6 2 7, 6 6 7, 2 6 7, 2 2 7))").unwrap();
+    let options = RasterizeOptions::new().burn_z(true);
+    rasterize(&dataset, &[1], &[polygon], &[1.], &options).unwrap();
+    let data = burned(&dataset);
+    assert_eq!(data[4 * 10 + 2], 8);
+    assert_eq!(data[0], 0);
+}
+
+#[test]
+fn test_rasterize_layers() {
+    let mut vector = Driver::get("Memory")
+        .unwrap()
+        .create_vector_only("")
+        .unwrap();
+    let mut layer = vector
+        .create_layer("zones", None, OGRwkbGeometryType::wkbPolygon)
+        .unwrap();
+    layer
+        .create_defn_fields(&[("zone", OGRFieldType::OFTInteger)])
+        .unwrap();
+    layer
+        .create_feature_fields(
+            Geometry::bbox(0., 0., 4., 4.).unwrap(),
+            &["zone"],
+            &[FieldValue::IntegerValue(3)],
+        )
+        .unwrap();
+    layer
+        .create_feature_fields(
+            Geometry::bbox(6., 6., 10., 10.).unwrap(),
+            &["zone"],
+            &[FieldValue::IntegerValue(7)],
+        )
+        .unwrap();
+
+    let dataset = rasterize_test_dataset();
+    let burn = BurnSource::Attribute("zone".to_string());
+    rasterize_layers(&dataset, &[1], &[&layer], &burn, &RasterizeOptions::new()).unwrap();
+    let data = burned(&dataset);
+    assert_eq!(data[9 * 10], 3);
+    assert_eq!(data[9], 7);
+    assert_eq!(data[5 * 10 + 5], 0);
+
+    let dataset = rasterize_test_dataset();
+    let burn = BurnSource::Values(vec![255.]);
+    rasterize_layers(&dataset, &[1], &[&layer], &burn, &RasterizeOptions::new()).unwrap();
+    let data = burned(&dataset);
+    assert_eq!(data.iter().filter(|&&v| v == 255).count(), 32);
+}
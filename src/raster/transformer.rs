@@ -0,0 +1,193 @@
+use crate::dataset::Dataset;
+use crate::spatial_ref::SpatialRef;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, CStringList};
+use crate::GeoTransform;
+use gdal_sys::{self, CPLErr, GDALTransformerFunc};
+use libc::{c_int, c_void};
+use std::mem;
+use std::ptr::null_mut;
+
+use crate::errors::*;
+
+/// The non-null function of a `GDALTransformerFunc`.
+type TransformerFn = unsafe extern "C" fn(
+    *mut c_void,
+    c_int,
+    c_int,
+    *mut f64,
+    *mut f64,
+    *mut f64,
+    *mut c_int,
+) -> c_int;
+
+/// Transforms between the pixel/line coordinates of a source and the pixel/line or
+/// georeferenced coordinates of a destination.
+///
+/// The transformation is chosen from the georeferencing of the datasets, i.e. a
+/// geotransform, GCPs or RPCs, followed by a reprojection if the spatial references
+/// differ. Options like `SRC_METHOD=GCP_TPS`, `SRC_SRS` or `DST_SRS` override it, see
+/// `GDALCreateGenImgProjTransformer2`.
+///
+/// ```no_run
+/// use gdal::raster::Transformer;
+/// use gdal::spatial_ref::SpatialRef;
+/// use gdal::Dataset;
+/// use std::path::Path;
+///
+/// let dataset = Dataset::open(Path::new("fixtures/tinymarble.png")).unwrap();
+/// let wgs84 = SpatialRef::from_epsg(4326).unwrap();
+/// let transformer = Transformer::with_dst_srs(&dataset, &wgs84, &[] as &[&str]).unwrap();
+/// let (mut x, mut y, mut z) = ([0.5], [0.5], [0.]);
+/// let success = transformer.transform_coords(&mut x, &mut y, &mut z).unwrap();
+/// assert!(success[0]);
+/// ```
+#[derive(Debug)]
+pub struct Transformer {
+    func: TransformerFn,
+    arg: *mut c_void,
+}
+
+impl Transformer {
+    /// Transform from pixel/line coordinates of `src` to pixel/line coordinates of `dst`.
+    pub fn new<S: AsRef<str>>(src: &Dataset, dst: &Dataset, options: &[S]) -> Result<Self> {
+        Transformer::with_options(Some(src), Some(dst), options)
+    }
+
+    /// Transform from pixel/line coordinates of `src` to georeferenced coordinates in
+    /// `dst_srs`.
+    pub fn with_dst_srs<S: AsRef<str>>(
+        src: &Dataset,
+        dst_srs: &SpatialRef,
+        options: &[S],
+    ) -> Result<Self> {
+        let mut options: Vec<String> = options.iter().map(|o| o.as_ref().to_string()).collect();
+        options.push(format!("DST_SRS={}", dst_srs.to_wkt()?));
+        Transformer::with_options(Some(src), None, &options)
+    }
+
+    /// Transform between `src` and `dst`. A missing dataset means georeferenced
+    /// coordinates, in the spatial reference given by `SRC_SRS` or `DST_SRS` if any.
+    pub fn with_options<S: AsRef<str>>(
+        src: Option<&Dataset>,
+        dst: Option<&Dataset>,
+        options: &[S],
+    ) -> Result<Self> {
+        let c_options = CStringList::new(options)?;
+        let arg = unsafe {
+            gdal_sys::GDALCreateGenImgProjTransformer2(
+                src.map_or(null_mut(), |src| src.c_dataset()),
+                dst.map_or(null_mut(), |dst| dst.c_dataset()),
+                c_options.as_ptr(),
+            )
+        };
+        if arg.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateGenImgProjTransformer2").into());
+        }
+        Ok(Transformer {
+            func: gdal_sys::GDALGenImgProjTransform,
+            arg,
+        })
+    }
+
+    /// Set the destination geotransform, which must be done before `approximate`.
+    pub(crate) fn set_dst_geo_transform(&mut self, geo_transform: &GeoTransform) {
+        unsafe {
+            gdal_sys::GDALSetGenImgProjTransformerDstGeoTransform(self.arg, geo_transform.as_ptr())
+        };
+    }
+
+    /// Wrap the transformer into one that interpolates between exactly transformed
+    /// points, with an error of at most `max_error`. A `max_error` of 0 keeps the
+    /// transformer as is.
+    pub fn approximate(self, max_error: f64) -> Result<Self> {
+        if max_error <= 0.0 {
+            return Ok(self);
+        }
+        let approx_arg =
+            unsafe { gdal_sys::GDALCreateApproxTransformer(Some(self.func), self.arg, max_error) };
+        if approx_arg.is_null() {
+            return Err(_last_null_pointer_err("GDALCreateApproxTransformer").into());
+        }
+        unsafe { gdal_sys::GDALApproxTransformerOwnsSubtransformer(approx_arg, 1) };
+        mem::forget(self);
+        Ok(Transformer {
+            func: gdal_sys::GDALApproxTransform,
+            arg: approx_arg,
+        })
+    }
+
+    /// Transform points from source to destination coordinates in place.
+    ///
+    /// Returns whether each point could be transformed. Points that failed are left
+    /// in an undefined state.
+    pub fn transform_coords(
+        &self,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+    ) -> Result<Vec<bool>> {
+        self._transform(false, x, y, z)
+    }
+
+    /// Transform points from destination to source coordinates in place, see
+    /// `transform_coords`.
+    pub fn transform_coords_inverse(
+        &self,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+    ) -> Result<Vec<bool>> {
+        self._transform(true, x, y, z)
+    }
+
+    fn _transform(
+        &self,
+        dst_to_src: bool,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+    ) -> Result<Vec<bool>> {
+        let nb_coords = x.len();
+        for len in &[y.len(), z.len()] {
+            if *len != nb_coords {
+                return Err(ErrorKind::BufferLengthMismatch {
+                    len: *len,
+                    expected: nb_coords,
+                }
+                .into());
+            }
+        }
+        let mut success: Vec<c_int> = vec![0; nb_coords];
+        let rv = unsafe {
+            (self.func)(
+                self.arg,
+                dst_to_src as c_int,
+                nb_coords as c_int,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                z.as_mut_ptr(),
+                success.as_mut_ptr(),
+            )
+        };
+        if rv == 0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure).into());
+        }
+        Ok(success.into_iter().map(|s| s != 0).collect())
+    }
+
+    pub fn c_transformer_func(&self) -> GDALTransformerFunc {
+        Some(self.func)
+    }
+
+    /// # Safety
+    /// The returned pointer is owned by the transformer, and must not be destroyed.
+    pub unsafe fn c_transformer_arg(&self) -> *mut c_void {
+        self.arg
+    }
+}
+
+impl Drop for Transformer {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GDALDestroyTransformer(self.arg) };
+    }
+}
@@ -1,5 +1,100 @@
+use crate::utils::_string;
 pub use gdal_sys::GDALDataType;
 
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+
+/// A `key=value` creation option passed to the driver when creating a raster
+/// dataset, e.g. `TILED=YES` or `COMPRESS=DEFLATE` for GeoTIFF.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RasterCreationOption<'a> {
+    pub key: &'a str,
+    pub value: &'a str,
+}
+
+/// Queries on a `GDALDataType::Type`, e.g. the value returned by `RasterBand::band_type`.
+///
+/// ```
+/// use gdal::raster::{GDALDataType, GdalDataTypeExt};
+///
+/// assert_eq!(GDALDataType::GDT_Int16.size_bytes(), 2);
+/// assert!(GDALDataType::GDT_CFloat32.is_complex());
+/// assert_eq!(
+///     GDALDataType::GDT_Byte.union(GDALDataType::GDT_Int16),
+///     GDALDataType::GDT_Int16
+/// );
+/// ```
+pub trait GdalDataTypeExt {
+    /// The size of a single value in bytes, or 0 for an unknown type.
+    fn size_bytes(&self) -> usize;
+    /// Whether the type holds integer values, including complex integers.
+    fn is_integer(&self) -> bool;
+    /// Whether the type holds floating point values, including complex floats.
+    fn is_floating(&self) -> bool;
+    /// Whether the type can hold negative values.
+    fn is_signed(&self) -> bool;
+    /// Whether the type holds complex values.
+    fn is_complex(&self) -> bool;
+    /// The smallest type that can hold all values of both types.
+    fn union(&self, other: GDALDataType::Type) -> GDALDataType::Type;
+    /// The GDAL name of the type, e.g. `"Int16"`.
+    fn name(&self) -> String;
+}
+
+impl GdalDataTypeExt for GDALDataType::Type {
+    fn size_bytes(&self) -> usize {
+        (unsafe { gdal_sys::GDALGetDataTypeSize(*self) } / 8) as usize
+    }
+
+    fn is_integer(&self) -> bool {
+        match *self {
+            GDALDataType::GDT_Byte
+            | GDALDataType::GDT_UInt16
+            | GDALDataType::GDT_Int16
+            | GDALDataType::GDT_UInt32
+            | GDALDataType::GDT_Int32
+            | GDALDataType::GDT_CInt16
+            | GDALDataType::GDT_CInt32 => true,
+            #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+            GDALDataType::GDT_Int64 | GDALDataType::GDT_UInt64 => true,
+            #[cfg(any(all(major_is_3, minor_ge_7), major_ge_4))]
+            GDALDataType::GDT_Int8 => true,
+            _ => false,
+        }
+    }
+
+    fn is_floating(&self) -> bool {
+        matches!(
+            *self,
+            GDALDataType::GDT_Float32
+                | GDALDataType::GDT_Float64
+                | GDALDataType::GDT_CFloat32
+                | GDALDataType::GDT_CFloat64
+        )
+    }
+
+    fn is_signed(&self) -> bool {
+        match *self {
+            GDALDataType::GDT_Byte | GDALDataType::GDT_UInt16 | GDALDataType::GDT_UInt32 => false,
+            #[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+            GDALDataType::GDT_UInt64 => false,
+            _ => self.is_integer() || self.is_floating(),
+        }
+    }
+
+    fn is_complex(&self) -> bool {
+        unsafe { gdal_sys::GDALDataTypeIsComplex(*self) != 0 }
+    }
+
+    fn union(&self, other: GDALDataType::Type) -> GDALDataType::Type {
+        unsafe { gdal_sys::GDALDataTypeUnion(*self, other) }
+    }
+
+    fn name(&self) -> String {
+        _string(unsafe { gdal_sys::GDALGetDataTypeName(*self) })
+    }
+}
+
 pub trait GdalType {
     fn gdal_type() -> GDALDataType::Type;
 }
@@ -39,3 +134,50 @@ impl GdalType for f64 {
         GDALDataType::GDT_Float64
     }
 }
+
+// GDAL 3.5 adds 64-bit integer types
+#[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+impl GdalType for i64 {
+    fn gdal_type() -> GDALDataType::Type {
+        GDALDataType::GDT_Int64
+    }
+}
+#[cfg(any(all(major_is_3, minor_ge_5), major_ge_4))]
+impl GdalType for u64 {
+    fn gdal_type() -> GDALDataType::Type {
+        GDALDataType::GDT_UInt64
+    }
+}
+
+// GDAL 3.7 adds a signed 8-bit integer type
+#[cfg(any(all(major_is_3, minor_ge_7), major_ge_4))]
+impl GdalType for i8 {
+    fn gdal_type() -> GDALDataType::Type {
+        GDALDataType::GDT_Int8
+    }
+}
+
+#[cfg(feature = "complex")]
+impl GdalType for Complex<i16> {
+    fn gdal_type() -> GDALDataType::Type {
+        GDALDataType::GDT_CInt16
+    }
+}
+#[cfg(feature = "complex")]
+impl GdalType for Complex<i32> {
+    fn gdal_type() -> GDALDataType::Type {
+        GDALDataType::GDT_CInt32
+    }
+}
+#[cfg(feature = "complex")]
+impl GdalType for Complex<f32> {
+    fn gdal_type() -> GDALDataType::Type {
+        GDALDataType::GDT_CFloat32
+    }
+}
+#[cfg(feature = "complex")]
+impl GdalType for Complex<f64> {
+    fn gdal_type() -> GDALDataType::Type {
+        GDALDataType::GDT_CFloat64
+    }
+}
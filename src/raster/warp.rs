@@ -1,12 +1,593 @@
 use crate::dataset::Dataset;
-use crate::utils::_last_cpl_err;
-use gdal_sys::{self, CPLErr, GDALResampleAlg};
-use libc::c_double;
+use crate::driver::Driver;
+use crate::progress::{_progress_args, ProgressFn};
+use crate::raster::{GDALDataType, GdalDataTypeExt, RasterCreationOption, Transformer};
+use crate::spatial_ref::SpatialRef;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, CStringList};
+use crate::vector::Geometry;
+use crate::GeoTransform;
+use gdal_sys::{self, CPLErr, GDALDatasetH, GDALResampleAlg, GDALWarpOptions};
+use libc::{c_double, c_int};
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
 use std::ptr::{null, null_mut};
 
 use crate::errors::*;
 
+/// Resampling algorithm used by `warp`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarpResampleAlg {
+    NearestNeighbour,
+    Bilinear,
+    Cubic,
+    CubicSpline,
+    Lanczos,
+    Average,
+    Mode,
+    Max,
+    Min,
+    Med,
+    Q1,
+    Q3,
+}
+
+impl WarpResampleAlg {
+    pub fn to_gdal(self) -> GDALResampleAlg::Type {
+        match self {
+            WarpResampleAlg::NearestNeighbour => GDALResampleAlg::GRA_NearestNeighbour,
+            WarpResampleAlg::Bilinear => GDALResampleAlg::GRA_Bilinear,
+            WarpResampleAlg::Cubic => GDALResampleAlg::GRA_Cubic,
+            WarpResampleAlg::CubicSpline => GDALResampleAlg::GRA_CubicSpline,
+            WarpResampleAlg::Lanczos => GDALResampleAlg::GRA_Lanczos,
+            WarpResampleAlg::Average => GDALResampleAlg::GRA_Average,
+            WarpResampleAlg::Mode => GDALResampleAlg::GRA_Mode,
+            WarpResampleAlg::Max => GDALResampleAlg::GRA_Max,
+            WarpResampleAlg::Min => GDALResampleAlg::GRA_Min,
+            WarpResampleAlg::Med => GDALResampleAlg::GRA_Med,
+            WarpResampleAlg::Q1 => GDALResampleAlg::GRA_Q1,
+            WarpResampleAlg::Q3 => GDALResampleAlg::GRA_Q3,
+        }
+    }
+}
+
+/// Options for `warp`, mirroring the fields of `GDALWarpOptions`.
+///
+/// ```no_run
+/// use gdal::raster::{warp, WarpOptions, WarpResampleAlg};
+/// # use gdal::{Dataset, Driver};
+/// # use std::path::Path;
+/// # let src = Dataset::open(Path::new("fixtures/tinymarble.png")).unwrap();
+/// # let dst = Driver::get("MEM").unwrap().create("", 10, 10, 3).unwrap();
+/// let options = WarpOptions::new()
+///     .resample_alg(WarpResampleAlg::Cubic)
+///     .dst_no_data(&[0.0])
+///     .memory_limit(256.0 * 1024.0 * 1024.0)
+///     .multithreaded(true);
+/// warp(&src, &dst, &options).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct WarpOptions {
+    resample_alg: WarpResampleAlg,
+    src_bands: Vec<isize>,
+    dst_bands: Vec<isize>,
+    src_no_data: Vec<f64>,
+    dst_no_data: Vec<f64>,
+    cutline: Option<Geometry>,
+    memory_limit: f64,
+    error_threshold: f64,
+    multithreaded: bool,
+    warp_options: Vec<(String, String)>,
+}
+
+impl Default for WarpOptions {
+    fn default() -> Self {
+        WarpOptions::new()
+    }
+}
+
+impl WarpOptions {
+    /// Nearest neighbour resampling of all bands, with GDAL's default memory limit.
+    pub fn new() -> Self {
+        WarpOptions {
+            resample_alg: WarpResampleAlg::NearestNeighbour,
+            src_bands: Vec::new(),
+            dst_bands: Vec::new(),
+            src_no_data: Vec::new(),
+            dst_no_data: Vec::new(),
+            cutline: None,
+            memory_limit: 0.0,
+            error_threshold: 0.0,
+            multithreaded: false,
+            warp_options: Vec::new(),
+        }
+    }
+
+    pub fn resample_alg(mut self, resample_alg: WarpResampleAlg) -> Self {
+        self.resample_alg = resample_alg;
+        self
+    }
+
+    /// Warp `src_bands[i]` into `dst_bands[i]`. By default, all source bands are warped
+    /// into the destination bands with the same index.
+    pub fn band_mapping(mut self, src_bands: &[isize], dst_bands: &[isize]) -> Self {
+        self.src_bands = src_bands.to_vec();
+        self.dst_bands = dst_bands.to_vec();
+        self
+    }
+
+    /// Source pixels with this value are ignored. Give one value per band, or a single
+    /// value used for all bands.
+    pub fn src_no_data(mut self, no_data: &[f64]) -> Self {
+        self.src_no_data = no_data.to_vec();
+        self
+    }
+
+    /// Destination pixels not covered by the source are set to this value. Give one value
+    /// per band, or a single value used for all bands.
+    ///
+    /// Unless `INIT_DEST` is set through `warp_option`, the destination is initialized
+    /// with this value before warping.
+    pub fn dst_no_data(mut self, no_data: &[f64]) -> Self {
+        self.dst_no_data = no_data.to_vec();
+        self
+    }
+
+    /// Only warp source pixels inside this polygon, given in source pixel/line coordinates.
+    pub fn cutline(mut self, cutline: Geometry) -> Self {
+        self.cutline = Some(cutline);
+        self
+    }
+
+    /// The memory in bytes available for a single warp chunk. 0 uses GDAL's default.
+    pub fn memory_limit(mut self, memory_limit: f64) -> Self {
+        self.memory_limit = memory_limit;
+        self
+    }
+
+    /// Maximum error in pixels when approximating the transformation. 0 uses the exact
+    /// transformation for every pixel.
+    pub fn error_threshold(mut self, error_threshold: f64) -> Self {
+        self.error_threshold = error_threshold;
+        self
+    }
+
+    /// Warp chunks in parallel with I/O, using all CPUs unless `NUM_THREADS` is set
+    /// through `warp_option`.
+    pub fn multithreaded(mut self, multithreaded: bool) -> Self {
+        self.multithreaded = multithreaded;
+        self
+    }
+
+    /// Set a warp option such as `INIT_DEST=0`, `SOURCE_EXTRA=1` or `CUTLINE_ALL_TOUCHED=TRUE`.
+    pub fn warp_option(mut self, key: &str, value: &str) -> Self {
+        self.warp_options.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// The source and destination band lists, defaulting to all bands of `src`.
+    fn band_lists(&self, src: &Dataset) -> Result<(Vec<c_int>, Vec<c_int>)> {
+        if self.src_bands.is_empty() {
+            Ok((1..=src.raster_count() as c_int)
+                .map(|band| (band, band))
+                .unzip())
+        } else if self.src_bands.len() == self.dst_bands.len() {
+            Ok((
+                self.src_bands.iter().map(|&b| b as c_int).collect(),
+                self.dst_bands.iter().map(|&b| b as c_int).collect(),
+            ))
+        } else {
+            Err(ErrorKind::InvalidWarpOptions {
+                msg: "source and destination band lists differ in length".to_string(),
+            }
+            .into())
+        }
+    }
+
+    fn has_warp_option(&self, key: &str) -> bool {
+        self.warp_options
+            .iter()
+            .any(|(k, _)| k.eq_ignore_ascii_case(key))
+    }
+}
+
+/// Expand per-band values, repeating a single value for all bands.
+fn _per_band(values: &[f64], band_count: usize) -> Result<Vec<f64>> {
+    match values.len() {
+        1 => Ok(vec![values[0]; band_count]),
+        n if n == band_count => Ok(values.to_vec()),
+        n => Err(ErrorKind::InvalidWarpOptions {
+            msg: format!("got {} nodata values for {} bands", n, band_count),
+        }
+        .into()),
+    }
+}
+
+/// Warp `src` into `dst`, using the georeferencing of both datasets.
+///
+/// The destination is processed in chunks, see `WarpOptions::memory_limit`.
+pub fn warp(src: &Dataset, dst: &Dataset, options: &WarpOptions) -> Result<()> {
+    _warp(src, dst, options, None)
+}
+
+/// Like `warp`, reporting the progress to `progress`.
+///
+/// The warp is cancelled if `progress` returns `ProgressStatus::Abort`.
+pub fn warp_with_progress(
+    src: &Dataset,
+    dst: &Dataset,
+    options: &WarpOptions,
+    progress: &mut ProgressFn,
+) -> Result<()> {
+    _warp(src, dst, options, Some(progress))
+}
+
+fn _warp(
+    src: &Dataset,
+    dst: &Dataset,
+    options: &WarpOptions,
+    mut progress: Option<&mut ProgressFn>,
+) -> Result<()> {
+    let transformer =
+        Transformer::new(src, dst, &[] as &[&str])?.approximate(options.error_threshold)?;
+    let c_operation = _with_c_warp_options(
+        src,
+        Some(dst),
+        options,
+        &transformer,
+        &mut progress,
+        |c_options| unsafe { gdal_sys::GDALCreateWarpOperation(c_options) },
+    )?;
+    if c_operation.is_null() {
+        return Err(_last_null_pointer_err("GDALCreateWarpOperation").into());
+    }
+
+    let (dst_x, dst_y) = dst.raster_size();
+    let rv = unsafe {
+        if options.multithreaded {
+            gdal_sys::GDALChunkAndWarpMulti(c_operation, 0, 0, dst_x as c_int, dst_y as c_int)
+        } else {
+            gdal_sys::GDALChunkAndWarpImage(c_operation, 0, 0, dst_x as c_int, dst_y as c_int)
+        }
+    };
+    unsafe { gdal_sys::GDALDestroyWarpOperation(c_operation) };
+    if rv != CPLErr::CE_None {
+        return Err(_last_cpl_err(rv).into());
+    }
+    Ok(())
+}
+
+/// Build the `GDALWarpOptions` for `options` and pass them to `f`, which must copy
+/// anything it keeps. Without `dst`, the working data type only depends on `src`.
+///
+/// The progress argument points into `progress`, which must outlive any use of the
+/// copied options.
+fn _with_c_warp_options<F, R>(
+    src: &Dataset,
+    dst: Option<&Dataset>,
+    options: &WarpOptions,
+    transformer: &Transformer,
+    progress: &mut Option<&mut ProgressFn>,
+    f: F,
+) -> Result<R>
+where
+    F: FnOnce(*mut GDALWarpOptions) -> R,
+{
+    let (mut src_bands, mut dst_bands) = options.band_lists(src)?;
+    let band_count = src_bands.len();
+
+    let mut working_data_type = GDALDataType::GDT_Unknown;
+    for (&src_band, &dst_band) in src_bands.iter().zip(dst_bands.iter()) {
+        working_data_type = working_data_type.union(src.rasterband(src_band as isize)?.band_type());
+        if let Some(dst) = dst {
+            working_data_type =
+                working_data_type.union(dst.rasterband(dst_band as isize)?.band_type());
+        }
+    }
+
+    let mut src_no_data_real = Vec::new();
+    let mut dst_no_data_real = Vec::new();
+    if !options.src_no_data.is_empty() {
+        src_no_data_real = _per_band(&options.src_no_data, band_count)?;
+    }
+    if !options.dst_no_data.is_empty() {
+        dst_no_data_real = _per_band(&options.dst_no_data, band_count)?;
+    }
+    let mut src_no_data_imag = vec![0.0; src_no_data_real.len()];
+    let mut dst_no_data_imag = vec![0.0; dst_no_data_real.len()];
+
+    let mut warp_options: Vec<String> = options
+        .warp_options
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    if !dst_no_data_real.is_empty() && !options.has_warp_option("INIT_DEST") {
+        warp_options.push("INIT_DEST=NO_DATA".to_string());
+    }
+    if options.multithreaded && !options.has_warp_option("NUM_THREADS") {
+        warp_options.push("NUM_THREADS=ALL_CPUS".to_string());
+    }
+    let c_warp_options = CStringList::new(&warp_options)?;
+
+    let (c_progress, c_progress_arg) = _progress_args(progress.as_mut());
+    let nullable = |values: &mut Vec<f64>| {
+        if values.is_empty() {
+            null_mut()
+        } else {
+            values.as_mut_ptr()
+        }
+    };
+
+    // The warp options only borrow the Rust owned buffers, so the pointers are reset
+    // before the options are destroyed.
+    let c_options = unsafe { gdal_sys::GDALCreateWarpOptions() };
+    if c_options.is_null() {
+        return Err(_last_null_pointer_err("GDALCreateWarpOptions").into());
+    }
+    let rv = unsafe {
+        let o: &mut GDALWarpOptions = &mut *c_options;
+        o.papszWarpOptions = c_warp_options.as_ptr();
+        o.dfWarpMemoryLimit = options.memory_limit;
+        o.eResampleAlg = options.resample_alg.to_gdal();
+        o.eWorkingDataType = working_data_type;
+        o.hSrcDS = src.c_dataset();
+        o.hDstDS = dst.map_or(null_mut(), |dst| dst.c_dataset());
+        o.nBandCount = band_count as c_int;
+        o.panSrcBands = src_bands.as_mut_ptr();
+        o.panDstBands = dst_bands.as_mut_ptr();
+        o.padfSrcNoDataReal = nullable(&mut src_no_data_real);
+        o.padfSrcNoDataImag = nullable(&mut src_no_data_imag);
+        o.padfDstNoDataReal = nullable(&mut dst_no_data_real);
+        o.padfDstNoDataImag = nullable(&mut dst_no_data_imag);
+        o.pfnProgress = c_progress;
+        o.pProgressArg = c_progress_arg;
+        o.pfnTransformer = transformer.c_transformer_func();
+        o.pTransformerArg = transformer.c_transformer_arg();
+        if let Some(cutline) = &options.cutline {
+            o.hCutline = cutline.c_geometry();
+        }
+
+        let rv = f(c_options);
+
+        o.papszWarpOptions = null_mut();
+        o.panSrcBands = null_mut();
+        o.panDstBands = null_mut();
+        o.padfSrcNoDataReal = null_mut();
+        o.padfSrcNoDataImag = null_mut();
+        o.padfDstNoDataReal = null_mut();
+        o.padfDstNoDataImag = null_mut();
+        o.hCutline = null_mut();
+        gdal_sys::GDALDestroyWarpOptions(c_options);
+        rv
+    };
+    Ok(rv)
+}
+
+/// The output size and georeferencing that covers a source dataset once warped into
+/// another spatial reference, at roughly the same resolution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SuggestedWarpOutput {
+    /// The output size in pixels and lines.
+    pub size: (usize, usize),
+    pub geo_transform: GeoTransform,
+    /// The output extent as `(min_x, min_y, max_x, max_y)`.
+    pub extent: (f64, f64, f64, f64),
+}
+
+impl SuggestedWarpOutput {
+    /// The pixel size in x and y direction.
+    pub fn resolution(&self) -> (f64, f64) {
+        (
+            self.geo_transform.pixel_width(),
+            self.geo_transform.pixel_height().abs(),
+        )
+    }
+}
+
+/// Compute the size and georeferencing of `src` once warped into `dst_srs`.
+pub fn suggested_warp_output(src: &Dataset, dst_srs: &SpatialRef) -> Result<SuggestedWarpOutput> {
+    let transformer = Transformer::with_dst_srs(src, dst_srs, &[] as &[&str])?;
+    let mut geo_transform = GeoTransform::default();
+    let mut extent = [0.0; 4];
+    let mut pixels: c_int = 0;
+    let mut lines: c_int = 0;
+    let rv = unsafe {
+        gdal_sys::GDALSuggestedWarpOutput2(
+            src.c_dataset(),
+            transformer.c_transformer_func(),
+            transformer.c_transformer_arg(),
+            geo_transform.as_mut_ptr(),
+            &mut pixels,
+            &mut lines,
+            extent.as_mut_ptr(),
+            0,
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(_last_cpl_err(rv).into());
+    }
+    Ok(SuggestedWarpOutput {
+        size: (pixels as usize, lines as usize),
+        geo_transform,
+        extent: (extent[0], extent[1], extent[2], extent[3]),
+    })
+}
+
+/// Create a new dataset with `driver` that covers `src` warped into `dst_srs`, and
+/// warp `src` into it.
+///
+/// The output has the size and georeferencing of `suggested_warp_output`, one band
+/// per warped source band, and the destination nodata values of `options`.
+/// `creation_options` are passed on to the driver.
+pub fn create_warped(
+    src: &Dataset,
+    dst_srs: &SpatialRef,
+    driver: &Driver,
+    filename: &str,
+    options: &WarpOptions,
+    creation_options: &[RasterCreationOption],
+) -> Result<Dataset> {
+    let output = suggested_warp_output(src, dst_srs)?;
+    let (src_bands, dst_bands) = options.band_lists(src)?;
+    let band_count = dst_bands.iter().cloned().max().unwrap_or(0);
+    if band_count == 0 {
+        return Err(ErrorKind::InvalidWarpOptions {
+            msg: "no bands to warp".to_string(),
+        }
+        .into());
+    }
+    let mut data_type = GDALDataType::GDT_Unknown;
+    for &src_band in &src_bands {
+        data_type = data_type.union(src.rasterband(src_band as isize)?.band_type());
+    }
+    if data_type == GDALDataType::GDT_Unknown {
+        return Err(ErrorKind::UnsupportedDataType { data_type }.into());
+    }
+
+    let dst = driver._create(
+        filename,
+        output.size.0 as isize,
+        output.size.1 as isize,
+        band_count as isize,
+        data_type,
+        creation_options,
+    )?;
+    dst.set_projection(&dst_srs.to_wkt()?)?;
+    dst.set_geo_transform(&output.geo_transform)?;
+    if !options.dst_no_data.is_empty() {
+        let no_data = _per_band(&options.dst_no_data, dst_bands.len())?;
+        for (&dst_band, &value) in dst_bands.iter().zip(no_data.iter()) {
+            dst.rasterband(dst_band as isize)?
+                .set_no_data_value(value)?;
+        }
+    }
+    warp(src, &dst, options)?;
+    Ok(dst)
+}
+
+/// A virtual dataset that warps a source dataset on the fly, when it is read.
+///
+/// It dereferences to a `Dataset`, and borrows the source dataset it reads from.
+pub struct WarpedVrt<'a> {
+    dataset: Dataset,
+    src: PhantomData<&'a Dataset>,
+}
+
+impl<'a> WarpedVrt<'a> {
+    /// # Safety
+    /// `c_dataset` must be a warped VRT reading from `src`.
+    unsafe fn from_c_dataset(c_dataset: GDALDatasetH, _src: &'a Dataset) -> Self {
+        WarpedVrt {
+            dataset: Dataset::from_c_dataset(c_dataset),
+            src: PhantomData,
+        }
+    }
+}
+
+impl<'a> Deref for WarpedVrt<'a> {
+    type Target = Dataset;
+
+    fn deref(&self) -> &Dataset {
+        &self.dataset
+    }
+}
+
+impl<'a> DerefMut for WarpedVrt<'a> {
+    fn deref_mut(&mut self) -> &mut Dataset {
+        &mut self.dataset
+    }
+}
+
+/// Create a virtual dataset that warps `src` into `dst_srs` on the fly, when it is read.
+///
+/// The output has the size and georeferencing of `suggested_warp_output`.
+pub fn create_warped_vrt<'a>(
+    src: &'a Dataset,
+    dst_srs: &SpatialRef,
+    options: &WarpOptions,
+) -> Result<WarpedVrt<'a>> {
+    let output = suggested_warp_output(src, dst_srs)?;
+    let dst_wkt = dst_srs.to_wkt()?;
+    let mut transformer = Transformer::with_dst_srs(src, dst_srs, &[] as &[&str])?;
+    transformer.set_dst_geo_transform(&output.geo_transform);
+    // GDALCreateWarpedVRT takes ownership of the transformer, and may already have
+    // destroyed it when it fails, so it is never dropped here. If creation fails
+    // before GDAL takes it, the transformer leaks rather than risk a double free.
+    let transformer = ManuallyDrop::new(transformer.approximate(options.error_threshold)?);
+
+    let mut geo_transform = output.geo_transform;
+    let c_dataset = _with_c_warp_options(
+        src,
+        None,
+        options,
+        &transformer,
+        &mut None,
+        |c_options| unsafe {
+            gdal_sys::GDALCreateWarpedVRT(
+                src.c_dataset(),
+                output.size.0 as c_int,
+                output.size.1 as c_int,
+                geo_transform.as_mut_ptr(),
+                c_options,
+            )
+        },
+    )?;
+    if c_dataset.is_null() {
+        return Err(_last_null_pointer_err("GDALCreateWarpedVRT").into());
+    }
+    let vrt = unsafe { WarpedVrt::from_c_dataset(c_dataset, src) };
+    vrt.set_projection(&dst_wkt)?;
+    Ok(vrt)
+}
+
+/// Create a virtual dataset that warps all bands of `src` into `dst_srs` on the fly,
+/// using the nodata values of `src`.
+///
+/// `max_error` is the maximum error in pixels when approximating the transformation.
+pub fn auto_create_warped_vrt<'a>(
+    src: &'a Dataset,
+    dst_srs: &SpatialRef,
+    resample_alg: WarpResampleAlg,
+    max_error: f64,
+) -> Result<WarpedVrt<'a>> {
+    let c_dst_wkt = CString::new(dst_srs.to_wkt()?)?;
+    let c_dataset = unsafe {
+        gdal_sys::GDALAutoCreateWarpedVRT(
+            src.c_dataset(),
+            null(),
+            c_dst_wkt.as_ptr(),
+            resample_alg.to_gdal(),
+            max_error,
+            null(),
+        )
+    };
+    if c_dataset.is_null() {
+        return Err(_last_null_pointer_err("GDALAutoCreateWarpedVRT").into());
+    }
+    Ok(unsafe { WarpedVrt::from_c_dataset(c_dataset, src) })
+}
+
+/// Reproject `src` into `dst` with bilinear resampling.
+///
+/// See `warp` for control over resampling, nodata and band mapping.
 pub fn reproject(src: &Dataset, dst: &Dataset) -> Result<()> {
+    _reproject(src, dst, None)
+}
+
+/// Like `reproject`, reporting the progress of the warp to `progress`.
+///
+/// The warp is cancelled if `progress` returns `ProgressStatus::Abort`.
+pub fn reproject_with_progress(
+    src: &Dataset,
+    dst: &Dataset,
+    progress: &mut ProgressFn,
+) -> Result<()> {
+    _reproject(src, dst, Some(progress))
+}
+
+fn _reproject(src: &Dataset, dst: &Dataset, mut progress: Option<&mut ProgressFn>) -> Result<()> {
+    let (c_progress, c_progress_arg) = _progress_args(progress.as_mut());
     let rv = unsafe {
         gdal_sys::GDALReprojectImage(
             src.c_dataset(),
@@ -16,8 +597,8 @@ pub fn reproject(src: &Dataset, dst: &Dataset) -> Result<()> {
             GDALResampleAlg::GRA_Bilinear,
             0.0,
             0.0 as c_double,
-            None,
-            null_mut(),
+            c_progress,
+            c_progress_arg,
             null_mut(),
         )
     };
@@ -1,14 +1,35 @@
 use gdal_sys::{self, CPLErr};
 use libc::c_char;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
+use std::ptr;
 
 use crate::errors::*;
+use crate::raster::RasterCreationOption;
 
 pub fn _string(raw_ptr: *const c_char) -> String {
     let c_str = unsafe { CStr::from_ptr(raw_ptr) };
     c_str.to_string_lossy().into_owned()
 }
 
+/// Copy a NULL-terminated list of C strings, such as a GDAL `CSL`, into a `Vec`.
+/// A NULL list yields an empty `Vec`.
+pub fn _string_array(raw_ptr: *mut *mut c_char) -> Vec<String> {
+    let mut strings = Vec::new();
+    if raw_ptr.is_null() {
+        return strings;
+    }
+    let mut i = 0;
+    loop {
+        let item = unsafe { *raw_ptr.add(i) };
+        if item.is_null() {
+            break;
+        }
+        strings.push(_string(item));
+        i += 1;
+    }
+    strings
+}
+
 // TODO: inspect if this is sane...
 pub fn _last_cpl_err(cpl_err_class: CPLErr::Type) -> ErrorKind {
     let last_err_no = unsafe { gdal_sys::CPLGetLastErrorNo() };
@@ -29,3 +50,49 @@ pub fn _last_null_pointer_err(method_name: &'static str) -> ErrorKind {
         msg: last_err_msg,
     }
 }
+
+/// A NULL terminated list of C strings, as expected by the `char **`
+/// parameters of GDAL (driver lists, open options, creation options...).
+///
+/// The `CString`s are kept alongside the pointers, so the list stays
+/// valid for as long as this value is alive.
+pub struct CStringList {
+    _strings: Vec<CString>,
+    ptrs: Vec<*mut c_char>,
+}
+
+impl CStringList {
+    pub fn new<S: AsRef<str>>(items: &[S]) -> Result<CStringList> {
+        let strings = items
+            .iter()
+            .map(|s| CString::new(s.as_ref()))
+            .collect::<std::result::Result<Vec<CString>, _>>()?;
+        let mut ptrs = strings
+            .iter()
+            .map(|s| s.as_ptr() as *mut c_char)
+            .collect::<Vec<_>>();
+        ptrs.push(ptr::null_mut());
+        Ok(CStringList {
+            _strings: strings,
+            ptrs,
+        })
+    }
+
+    pub fn as_ptr(&self) -> *mut *mut c_char {
+        self.ptrs.as_ptr() as *mut *mut c_char
+    }
+}
+
+/// Build the `KEY=VALUE` list expected by GDAL from raster creation options.
+pub fn _creation_options_list(options: &[RasterCreationOption]) -> Result<CStringList> {
+    let options = options
+        .iter()
+        .map(|option| format!("{}={}", option.key, option.value))
+        .collect::<Vec<_>>();
+    CStringList::new(&options)
+}
+
+/// Returns the list pointer, or NULL if there is no list.
+pub fn _opt_list_ptr(list: &Option<CStringList>) -> *mut *mut c_char {
+    list.as_ref().map_or(ptr::null_mut(), |l| l.as_ptr())
+}
@@ -101,6 +101,20 @@ impl<'a> Feature<'a> {
         }
     }
 
+    /// Returns whether a value has been assigned to the named field.
+    pub fn field_is_set(&self, name: &str) -> Result<bool> {
+        let c_name = CString::new(name)?;
+        let field_id = unsafe { gdal_sys::OGR_F_GetFieldIndex(self.c_feature, c_name.as_ptr()) };
+        if field_id == -1 {
+            return Err(ErrorKind::InvalidFieldName {
+                field_name: name.to_string(),
+                method_name: "OGR_F_GetFieldIndex",
+            }
+            .into());
+        }
+        Ok(unsafe { gdal_sys::OGR_F_IsFieldSet(self.c_feature, field_id) } != 0)
+    }
+
     #[cfg(feature = "datetime")]
     fn get_field_datetime(&self, field_id: c_int) -> Result<DateTime<FixedOffset>> {
         let mut year: c_int = 0;
@@ -8,6 +8,7 @@ use gdal_sys::{
     self, GDALMajorObjectH, OGREnvelope, OGRErr, OGRFieldDefnH, OGRFieldType, OGRLayerH,
 };
 use libc::c_int;
+use std::ops::{Deref, DerefMut};
 use std::ptr::null_mut;
 use std::{ffi::CString, marker::PhantomData};
 
@@ -62,8 +63,9 @@ impl<'a> Layer<'a> {
         self.c_layer
     }
 
-    /// Iterate over all features in this layer.
+    /// Iterate over all features in this layer, starting from the first one.
     pub fn features(&self) -> FeatureIterator {
+        unsafe { gdal_sys::OGR_L_ResetReading(self.c_layer) };
         FeatureIterator::_with_layer(self)
     }
 
@@ -155,6 +157,89 @@ impl<'a> Layer<'a> {
         }
         SpatialRef::from_c_obj(c_obj)
     }
+
+    /// Start a transaction on this layer, for drivers that support layer level
+    /// transactions but not dataset level ones.
+    ///
+    /// The returned `LayerTransaction` dereferences to the layer. Changes are applied
+    /// with `LayerTransaction::commit`, and rolled back if the transaction is dropped
+    /// without being committed.
+    pub fn start_transaction<'l>(&'l mut self) -> Result<LayerTransaction<'a, 'l>> {
+        let rv = unsafe { gdal_sys::OGR_L_StartTransaction(self.c_layer) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(ErrorKind::OgrError {
+                err: rv,
+                method_name: "OGR_L_StartTransaction",
+            }
+            .into());
+        }
+        Ok(LayerTransaction {
+            layer: self,
+            rollback_on_drop: true,
+        })
+    }
+}
+
+/// A transaction on a `Layer`, started with `Layer::start_transaction`.
+///
+/// The transaction is rolled back on drop, unless it was committed.
+#[must_use = "the transaction is rolled back when dropped, unless committed"]
+pub struct LayerTransaction<'a, 'l> {
+    layer: &'l mut Layer<'a>,
+    rollback_on_drop: bool,
+}
+
+impl<'a, 'l> LayerTransaction<'a, 'l> {
+    /// Commit the changes made during the transaction.
+    pub fn commit(mut self) -> Result<()> {
+        self.rollback_on_drop = false;
+        let rv = unsafe { gdal_sys::OGR_L_CommitTransaction(self.layer.c_layer) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(ErrorKind::OgrError {
+                err: rv,
+                method_name: "OGR_L_CommitTransaction",
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Discard the changes made during the transaction.
+    pub fn rollback(mut self) -> Result<()> {
+        self.rollback_on_drop = false;
+        let rv = unsafe { gdal_sys::OGR_L_RollbackTransaction(self.layer.c_layer) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(ErrorKind::OgrError {
+                err: rv,
+                method_name: "OGR_L_RollbackTransaction",
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'l> Deref for LayerTransaction<'a, 'l> {
+    type Target = Layer<'a>;
+
+    fn deref(&self) -> &Layer<'a> {
+        self.layer
+    }
+}
+
+impl<'a, 'l> DerefMut for LayerTransaction<'a, 'l> {
+    fn deref_mut(&mut self) -> &mut Layer<'a> {
+        self.layer
+    }
+}
+
+impl<'a, 'l> Drop for LayerTransaction<'a, 'l> {
+    fn drop(&mut self) {
+        if self.rollback_on_drop {
+            // errors can't be reported from drop
+            unsafe { gdal_sys::OGR_L_RollbackTransaction(self.layer.c_layer) };
+        }
+    }
 }
 
 pub struct FeatureIterator<'a> {
@@ -22,13 +22,15 @@ mod geo_to_gdal;
 mod geometry;
 mod layer;
 mod ops;
+mod sql;
 
 pub use defn::{Defn, Field, FieldIterator};
 pub use feature::{Feature, FieldValue};
 pub use gdal_sys::{OGRFieldType, OGRwkbGeometryType};
 pub use geometry::Geometry;
-pub use layer::{FeatureIterator, FieldDefn, Layer};
+pub use layer::{FeatureIterator, FieldDefn, Layer, LayerTransaction};
 pub use ops::GeometryIntersection;
+pub use sql::{ResultSet, SqlDialect};
 
 use crate::errors::Result;
 
@@ -0,0 +1,61 @@
+use crate::dataset::Dataset;
+use crate::vector::Layer;
+use std::ops::Deref;
+
+/// The SQL dialect of `Dataset::execute_sql`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqlDialect {
+    /// The native SQL of the driver if it has one (e.g. for PostgreSQL or GeoPackage),
+    /// OGR SQL otherwise.
+    Default,
+    /// [OGR SQL](https://gdal.org/user/ogr_sql_dialect.html)
+    Ogr,
+    /// [SQLite SQL](https://gdal.org/user/sql_sqlite_dialect.html), with SpatiaLite
+    /// functions if available.
+    Sqlite,
+}
+
+impl SqlDialect {
+    pub(crate) fn name(self) -> Option<&'static str> {
+        match self {
+            SqlDialect::Default => None,
+            SqlDialect::Ogr => Some("OGRSQL"),
+            SqlDialect::Sqlite => Some("SQLITE"),
+        }
+    }
+}
+
+/// The layer returned by `Dataset::execute_sql`.
+///
+/// It dereferences to a `Layer` and is released with `GDALDatasetReleaseResultSet`
+/// when dropped.
+pub struct ResultSet<'a> {
+    layer: Layer<'a>,
+    dataset: &'a Dataset,
+}
+
+impl<'a> ResultSet<'a> {
+    /// Wraps a result set layer returned by `GDALDatasetExecuteSQL`.
+    ///
+    /// # Safety
+    /// `layer` must be a result set of `dataset`
+    pub(crate) unsafe fn new(dataset: &'a Dataset, layer: Layer<'a>) -> ResultSet<'a> {
+        ResultSet { layer, dataset }
+    }
+}
+
+impl<'a> Deref for ResultSet<'a> {
+    type Target = Layer<'a>;
+
+    fn deref(&self) -> &Layer<'a> {
+        &self.layer
+    }
+}
+
+impl<'a> Drop for ResultSet<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::GDALDatasetReleaseResultSet(self.dataset.c_dataset(), self.layer.c_layer())
+        };
+    }
+}
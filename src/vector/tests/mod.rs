@@ -1,4 +1,6 @@
-use super::{Feature, FeatureIterator, FieldValue, Geometry, OGRFieldType, OGRwkbGeometryType};
+use super::{
+    Feature, FeatureIterator, FieldValue, Geometry, OGRFieldType, OGRwkbGeometryType, SqlDialect,
+};
 use crate::spatial_ref::SpatialRef;
 use crate::{assert_almost_eq, Dataset, Driver, errors};
 use std::path::Path;
@@ -338,3 +340,182 @@ fn test_write_features() {
     assert_eq!(ft.field("Value").unwrap().into_real(), Some(45.78));
     assert_eq!(ft.field("Int_value").unwrap().into_int(), Some(1));
 }
+
+#[test]
+fn test_dataset_transaction() {
+    let driver = Driver::get("GPKG").unwrap();
+    let mut ds = driver
+        .create_vector_only("/vsimem/test_dataset_transaction.gpkg")
+        .unwrap();
+    ds.create_layer("points", None, OGRwkbGeometryType::wkbPoint)
+        .unwrap();
+
+    {
+        let mut tx = ds.start_transaction().unwrap();
+        let mut layer = tx.layer(0).unwrap();
+        layer
+            .create_feature(Geometry::from_wkt("POINT (1 2)").unwrap())
+            .unwrap();
+        // rolled back here
+    }
+    assert_eq!(ds.layer(0).unwrap().features().count(), 0);
+
+    let mut tx = ds.start_transaction().unwrap();
+    {
+        let mut layer = tx.layer(0).unwrap();
+        layer
+            .create_feature(Geometry::from_wkt("POINT (1 2)").unwrap())
+            .unwrap();
+        layer
+            .create_feature(Geometry::from_wkt("POINT (3 4)").unwrap())
+            .unwrap();
+    }
+    tx.commit().unwrap();
+    assert_eq!(ds.layer(0).unwrap().features().count(), 2);
+
+    let mut tx = ds.start_transaction().unwrap();
+    tx.layer(0)
+        .unwrap()
+        .create_feature(Geometry::from_wkt("POINT (5 6)").unwrap())
+        .unwrap();
+    tx.rollback().unwrap();
+    assert_eq!(ds.layer(0).unwrap().features().count(), 2);
+}
+
+#[test]
+fn test_dataset_transaction_unsupported() {
+    let driver = Driver::get("Memory").unwrap();
+    let mut ds = driver.create_vector_only("").unwrap();
+    assert!(ds.start_transaction().is_err());
+}
+
+#[test]
+fn test_dataset_forced_transaction() {
+    let driver = Driver::get("GPKG").unwrap();
+    let mut ds = driver
+        .create_vector_only("/vsimem/test_dataset_forced_transaction.gpkg")
+        .unwrap();
+    ds.create_layer("points", None, OGRwkbGeometryType::wkbPoint)
+        .unwrap();
+    let mut tx = ds.start_forced_transaction().unwrap();
+    tx.layer(0)
+        .unwrap()
+        .create_feature(Geometry::from_wkt("POINT (1 2)").unwrap())
+        .unwrap();
+    tx.commit().unwrap();
+    assert_eq!(ds.layer(0).unwrap().features().count(), 1);
+
+    // the Memory driver supports neither native nor emulated transactions
+    let driver = Driver::get("Memory").unwrap();
+    let mut ds = driver.create_vector_only("").unwrap();
+    let err = ds.start_forced_transaction().err().unwrap();
+    assert!(matches!(
+        err.kind_ref(),
+        errors::ErrorKind::OgrError {
+            method_name: "GDALDatasetStartTransaction",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_layer_transaction() {
+    let driver = Driver::get("GPKG").unwrap();
+    let mut ds = driver
+        .create_vector_only("/vsimem/test_layer_transaction.gpkg")
+        .unwrap();
+    let mut layer = ds
+        .create_layer("points", None, OGRwkbGeometryType::wkbPoint)
+        .unwrap();
+    let mut tx = layer.start_transaction().unwrap();
+    tx.create_feature(Geometry::from_wkt("POINT (1 2)").unwrap())
+        .unwrap();
+    tx.commit().unwrap();
+    assert_eq!(layer.features().count(), 1);
+}
+
+#[test]
+fn test_execute_sql() {
+    let mut ds = Dataset::open(fixture!("roads.geojson")).unwrap();
+    let layer_name = ds.layer(0).unwrap().name();
+
+    let query = format!(
+        "SELECT kind, highway FROM \"{}\" WHERE highway = 'pedestrian'",
+        layer_name
+    );
+    let result_set = ds
+        .execute_sql(&query, None, SqlDialect::Default)
+        .unwrap()
+        .unwrap();
+    assert_eq!(result_set.features().count(), 10);
+    assert_eq!(result_set.defn().fields().count(), 2);
+    for feature in result_set.features() {
+        assert_eq!(
+            feature.field("highway").unwrap().into_string(),
+            Some("pedestrian".to_string())
+        );
+    }
+}
+
+#[test]
+fn test_execute_sql_with_spatial_filter() {
+    let mut ds = Dataset::open(fixture!("roads.geojson")).unwrap();
+    let layer_name = ds.layer(0).unwrap().name();
+
+    let bbox = Geometry::bbox(26.1017, 44.4297, 26.1025, 44.4303).unwrap();
+    let query = format!("SELECT * FROM \"{}\"", layer_name);
+    let result_set = ds
+        .execute_sql(&query, Some(&bbox), SqlDialect::Ogr)
+        .unwrap()
+        .unwrap();
+    assert_eq!(result_set.features().count(), 7);
+}
+
+#[test]
+fn test_execute_sql_sqlite_dialect() {
+    let mut ds = Dataset::open(fixture!("roads.geojson")).unwrap();
+    let layer_name = ds.layer(0).unwrap().name();
+
+    let query = format!(
+        "SELECT highway, COUNT(*) AS n FROM \"{}\" GROUP BY highway ORDER BY n DESC",
+        layer_name
+    );
+    let result_set = ds
+        .execute_sql(&query, None, SqlDialect::Sqlite)
+        .unwrap()
+        .unwrap();
+    let feature = result_set.features().next().unwrap();
+    assert_eq!(
+        feature.field("highway").unwrap().into_string(),
+        Some("pedestrian".to_string())
+    );
+    assert_eq!(feature.field("n").unwrap().into_int(), Some(10));
+}
+
+#[test]
+fn test_execute_sql_without_result_set() {
+    let driver = Driver::get("GPKG").unwrap();
+    let mut ds = driver
+        .create_vector_only("/vsimem/test_execute_sql.gpkg")
+        .unwrap();
+    let mut layer = ds
+        .create_layer("points", None, OGRwkbGeometryType::wkbPoint)
+        .unwrap();
+    layer
+        .create_feature(Geometry::from_wkt("POINT (1 2)").unwrap())
+        .unwrap();
+
+    assert!(ds
+        .execute_sql("DELETE FROM points", None, SqlDialect::Default)
+        .unwrap()
+        .is_none());
+    assert_eq!(ds.layer(0).unwrap().features().count(), 0);
+}
+
+#[test]
+fn test_execute_sql_invalid() {
+    let ds = Dataset::open(fixture!("roads.geojson")).unwrap();
+    assert!(ds
+        .execute_sql("SELECT * FROM no_such_layer", None, SqlDialect::Default)
+        .is_err());
+}